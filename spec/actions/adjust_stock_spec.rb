@@ -0,0 +1,102 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.adjust_stock' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'applies the delta to the quantity found by SKU and re-reads the result' do
+    mock_server.mock_endpoint(:get, '/products?sku=TS-001', [
+      { 'id' => 10, 'sku' => 'TS-001', 'type' => 'simple', 'parent_id' => 0, 'manage_stock' => true, 'stock_quantity' => 5 }
+    ])
+    mock_server.mock_endpoint(:put, '/products/10', { 'id' => 10, 'stock_quantity' => 3 })
+    mock_server.mock_endpoint(:get, '/products/10',
+      { 'id' => 10, 'sku' => 'TS-001', 'manage_stock' => true, 'stock_quantity' => 3, 'stock_status' => 'instock' })
+
+    response = tester.execute_action('adjust_stock', { 'sku' => 'TS-001', 'delta' => -2 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['previous_quantity']).to eq(5)
+    expect(data['stock_quantity']).to eq(3)
+    expect(data['manage_stock_enabled']).to eq(false)
+    expect(data['concurrent_change']).to eq(false)
+  end
+
+  it 'reports a concurrent change when the re-read quantity differs from the expected one' do
+    mock_server.mock_endpoint(:get, '/products/10',
+      { 'id' => 10, 'type' => 'simple', 'parent_id' => 0, 'manage_stock' => true, 'stock_quantity' => 5 })
+    mock_server.mock_endpoint(:put, '/products/10', { 'id' => 10, 'stock_quantity' => 3 })
+
+    response = tester.execute_action('adjust_stock', { 'productId' => 10, 'delta' => -2 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['expected_quantity']).to eq(3)
+    expect(data['concurrent_change']).to eq(true)
+  end
+
+  it 'enables stock management on a variation that does not manage stock yet' do
+    mock_server.mock_endpoint(:get, '/products/20/variations/21',
+      { 'id' => 21, 'manage_stock' => false, 'stock_quantity' => nil })
+    mock_server.mock_endpoint(:put, '/products/20/variations/21', { 'id' => 21, 'manage_stock' => true, 'stock_quantity' => 4 })
+
+    response = tester.execute_action('adjust_stock', { 'productId' => 20, 'variationId' => 21, 'delta' => 4 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['manage_stock_enabled']).to eq(true)
+    expect(data['parent_id']).to eq(20)
+  end
+
+  it 'adjusts the parent product when the variation uses the parent stock' do
+    mock_server.mock_endpoint(:get, '/products?sku=TS-001-M', [
+      { 'id' => 21, 'sku' => 'TS-001-M', 'type' => 'variation', 'parent_id' => 20, 'manage_stock' => 'parent' }
+    ])
+    mock_server.mock_endpoint(:get, '/products/20',
+      { 'id' => 20, 'type' => 'variable', 'parent_id' => 0, 'manage_stock' => true, 'stock_quantity' => 8 })
+    mock_server.mock_endpoint(:put, '/products/20', { 'id' => 20, 'stock_quantity' => 7 })
+
+    response = tester.execute_action('adjust_stock', { 'sku' => 'TS-001-M', 'delta' => -1 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(20)
+    expect(data['expected_quantity']).to eq(7)
+  end
+
+  it 'continues with an empty result when the SKU is unknown and on_not_found is continue' do
+    mock_server.mock_endpoint(:get, '/products?sku=NOPE', [])
+
+    response = tester.execute_action('adjust_stock', { 'sku' => 'NOPE', 'delta' => 1, 'on_not_found' => 'continue' })
+
+    expect(JSON.parse(response.serialized_output)).to eq({})
+  end
+
+  it 'raises an error if delta is missing' do
+    expect {
+      tester.execute_action('adjust_stock', { 'productId' => 10 })
+    }.to raise_error(AppBridge::MisconfiguredError, /delta parameter is required/)
+  end
+end
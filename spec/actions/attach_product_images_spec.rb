@@ -0,0 +1,104 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.attach_product_images' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'sets the featured image and replaces the gallery in the given order' do
+    mock_server.mock_endpoint(:get, '/products/10', {
+      'id' => 10,
+      'images' => [{ 'id' => 1 }, { 'id' => 2 }, { 'id' => 3 }]
+    })
+    mock_server.mock_endpoint(:post, '/wp-json/wp/v2/media/50', { 'id' => 50, 'alt_text' => 'Röd t-shirt framifrån' })
+    mock_server.mock_endpoint(:put, '/products/10', {
+      'id' => 10,
+      'images' => [{ 'id' => 50 }, { 'id' => 52 }, { 'id' => 60, 'src' => 'https://cdn.example.com/ts-back.jpg' }]
+    })
+
+    response = tester.execute_action('attach_product_images', {
+      'productId' => 10,
+      'featured_image' => { 'media_id' => 50, 'alt' => 'Röd t-shirt framifrån' },
+      'gallery' => [
+        { 'media_id' => 52 },
+        { 'src' => 'https://cdn.example.com/ts-back.jpg', 'alt' => 'Baksida' }
+      ]
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['images'].map { |i| i['id'] }).to eq([50, 52, 60])
+  end
+
+  it 'keeps the current featured image when appending to the gallery' do
+    mock_server.mock_endpoint(:get, '/products/10', { 'id' => 10, 'images' => [{ 'id' => 1 }, { 'id' => 2 }] })
+    mock_server.mock_endpoint(:put, '/products/10', { 'id' => 10, 'images' => [{ 'id' => 1 }, { 'id' => 2 }, { 'id' => 52 }] })
+
+    response = tester.execute_action('attach_product_images', {
+      'productId' => 10,
+      'gallery' => [{ 'media_id' => 52 }],
+      'gallery_mode' => 'append'
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['images'].map { |i| i['id'] }).to eq([1, 2, 52])
+  end
+
+  it 'raises an error if an image has neither media_id nor src' do
+    mock_server.mock_endpoint(:get, '/products/10', { 'id' => 10, 'images' => [] })
+
+    expect {
+      tester.execute_action('attach_product_images', { 'productId' => 10, 'gallery' => [{ 'alt' => 'Saknas' }] })
+    }.to raise_error(AppBridge::MisconfiguredError, /media_id or a src/)
+  end
+
+  it 'raises a misconfigured error when WordPress rejects the consumer key for media updates' do
+    mock_server.mock_endpoint(:get, '/products/10', { 'id' => 10, 'images' => [] })
+    mock_server.mock_endpoint(:post, '/wp-json/wp/v2/media/50', {
+      'code' => 'rest_cannot_edit',
+      'message' => 'Sorry, you are not allowed to edit this post.'
+    }, status: 401)
+
+    expect {
+      tester.execute_action('attach_product_images', {
+        'productId' => 10,
+        'featured_image' => { 'media_id' => 50, 'alt' => 'Röd t-shirt' }
+      })
+    }.to raise_error(AppBridge::MisconfiguredError, /application password/)
+  end
+
+  it 'raises UnsupportedError for base64 image content' do
+    mock_server.mock_endpoint(:get, '/products/10', { 'id' => 10, 'images' => [] })
+
+    expect {
+      tester.execute_action('attach_product_images', {
+        'productId' => 10,
+        'gallery' => [{ 'base64' => 'iVBORw0KGgo=' }]
+      })
+    }.to raise_error(AppBridge::UnsupportedError, /only send text bodies/)
+  end
+end
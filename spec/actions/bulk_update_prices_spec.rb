@@ -0,0 +1,107 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.bulk_update_prices' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'previews percentage changes rounded to the wanted ending without writing' do
+    mock_server.mock_endpoint(:get, '/products?category=15&page=1&per_page=100', [
+      { 'id' => 10, 'type' => 'simple', 'sku' => 'TS-001', 'name' => 'T-shirt', 'regular_price' => '249', 'sale_price' => '' },
+      { 'id' => 11, 'type' => 'simple', 'sku' => 'TS-002', 'name' => 'Presentkort', 'regular_price' => '' }
+    ])
+
+    response = tester.execute_action('bulk_update_prices', {
+      'category' => 15,
+      'adjustment' => -20,
+      'rounding' => 'ending',
+      'price_ending' => 0.9,
+      'preview' => true
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['preview']).to eq(true)
+    expect(data['has_more']).to eq(false)
+    expect(data['items'].first['new_price']).to eq('198.90')
+    expect(data['skipped'].map { |s| s['id'] }).to eq([11])
+  end
+
+  it 'sets sale prices on variations through the variation batch endpoint' do
+    mock_server.mock_endpoint(:get, '/products?tag=7&page=1&per_page=100', [
+      { 'id' => 20, 'type' => 'variable', 'name' => 'Hoodie' }
+    ])
+    mock_server.mock_endpoint(:get, '/products/20/variations?page=1&per_page=100', [
+      { 'id' => 21, 'sku' => 'HD-S', 'regular_price' => '499', 'sale_price' => '' },
+      { 'id' => 22, 'sku' => 'HD-M', 'regular_price' => '549', 'sale_price' => '' }
+    ])
+    mock_server.mock_endpoint(:post, '/products/20/variations/batch', {
+      'update' => [{ 'id' => 21, 'sale_price' => '399' }, { 'id' => 22, 'sale_price' => '449' }]
+    })
+
+    response = tester.execute_action('bulk_update_prices', {
+      'tag' => 7,
+      'adjustment_type' => 'fixed',
+      'adjustment' => -100,
+      'apply_to' => 'sale_price',
+      'date_on_sale_from' => '2026-06-01T00:00:00',
+      'date_on_sale_to' => '2026-06-30T23:59:59',
+      'rounding' => 'whole'
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items'].map { |i| i['new_price'] }).to eq(['399', '449'])
+    expect(data['items'].map { |i| i['parent_id'] }).to eq([20, 20])
+    expect(data['errors']).to eq([])
+  end
+
+  it 'skips products that already have the new price' do
+    mock_server.mock_endpoint(:get, '/products?sku=MG-1,MG-2&page=1&per_page=100', [
+      { 'id' => 30, 'type' => 'simple', 'sku' => 'MG-1', 'regular_price' => '200', 'sale_price' => '150.00' },
+      { 'id' => 31, 'type' => 'simple', 'sku' => 'MG-2', 'regular_price' => '300', 'sale_price' => '' }
+    ])
+    mock_server.mock_endpoint(:post, '/products/batch', {
+      'update' => [{ 'id' => 31, 'sale_price' => '225' }]
+    })
+
+    response = tester.execute_action('bulk_update_prices', {
+      'skus' => ['MG-1', 'MG-2'],
+      'adjustment' => -25,
+      'apply_to' => 'sale_price'
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items'].map { |i| i['id'] }).to eq([31])
+    expect(data['skipped']).to eq([{ 'id' => 30, 'sku' => 'MG-1', 'reason' => 'Price is already 150' }])
+  end
+
+  it 'raises an error if no selection is given' do
+    expect {
+      tester.execute_action('bulk_update_prices', { 'adjustment' => 10 })
+    }.to raise_error(AppBridge::MisconfiguredError, /Select products/)
+  end
+end
@@ -76,4 +76,70 @@ RSpec.describe 'actions.create_or_update_customer' do
     mock_server.mock_endpoint(:put, '/customers/123', { 'id' => 123 }, status: 200)
     tester.execute_action('create_or_update_customer', input)
   end
+
+  it 'updates the existing customer when matching by email finds a match' do
+    input = { 'match_by' => 'email', 'email' => 'befintlig@test.se', 'first_name' => 'Kalle' }
+
+    mock_server.mock_endpoint(:get, '/customers?email=befintlig%40test.se&role=all', [
+      { 'id' => 321, 'email' => 'befintlig@test.se' }
+    ])
+    mock_server.mock_endpoint(:put, '/customers/321', { 'id' => 321, 'first_name' => 'Kalle' }, status: 200)
+
+    response = tester.execute_action('create_or_update_customer', input)
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(321)
+    expect(data['operation']).to eq('updated')
+  end
+
+  it 'creates the customer when matching by email finds no match' do
+    input = { 'match_by' => 'email', 'email' => 'ny@test.se' }
+
+    mock_server.mock_endpoint(:get, '/customers?email=ny%40test.se&role=all', [])
+    mock_server.mock_endpoint(:post, '/customers', { 'id' => 654, 'email' => 'ny@test.se' }, status: 201)
+
+    response = tester.execute_action('create_or_update_customer', input)
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(654)
+    expect(data['operation']).to eq('created')
+  end
+
+  it 'ignores customers whose email differs from the one searched for' do
+    input = { 'match_by' => 'email', 'email' => 'Anna@Test.se' }
+
+    mock_server.mock_endpoint(:get, '/customers?email=Anna%40Test.se&role=all', [
+      { 'id' => 400, 'email' => 'anna@test.se.example.com' },
+      { 'id' => 401, 'email' => 'anna@test.se' }
+    ])
+    mock_server.mock_endpoint(:put, '/customers/401', { 'id' => 401, 'email' => 'anna@test.se' }, status: 200)
+
+    response = tester.execute_action('create_or_update_customer', input)
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(401)
+    expect(data['operation']).to eq('updated')
+  end
+
+  it 'raises an error when matching by email without an email address' do
+    expect {
+      tester.execute_action('create_or_update_customer', { 'match_by' => 'email', 'first_name' => 'Kalle' })
+    }.to raise_error(AppBridge::MisconfiguredError, /email is required/)
+  end
+
+  it 'updates existing meta keys by id when meta_set is given' do
+    mock_server.mock_endpoint(:get, '/customers/123', {
+      'id' => 123,
+      'meta_data' => [{ 'id' => 70, 'key' => 'erp_id', 'value' => 'C-1' }]
+    })
+    mock_server.mock_endpoint(:put, '/customers/123', {
+      'id' => 123,
+      'meta_data' => [{ 'id' => 70, 'key' => 'erp_id', 'value' => 'C-2' }]
+    })
+
+    response = tester.execute_action('create_or_update_customer', { 'customerId' => 123, 'meta_set' => { 'erp_id' => 'C-2' } })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['meta_data']).to eq([{ 'id' => 70, 'key' => 'erp_id', 'value' => 'C-2' }])
+  end
 end
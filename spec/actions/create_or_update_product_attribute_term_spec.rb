@@ -0,0 +1,92 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.create_or_update_product_attribute_term' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'returns the existing term when ensuring a term whose name already exists' do
+    mock_server.mock_endpoint(:get, '/products/attributes/1/terms?search=r%C3%B6d&page=1&per_page=100', [
+      { 'id' => 12, 'name' => 'Rödbrun' },
+      { 'id' => 10, 'name' => 'Röd' }
+    ])
+
+    response = tester.execute_action('create_or_update_product_attribute_term', {
+      'attributeId' => 1,
+      'name' => 'röd',
+      'ensure_exists' => true
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(10)
+    expect(data['operation']).to eq('existing')
+  end
+
+  it 'matches an existing term whose name WooCommerce returns HTML-escaped' do
+    mock_server.mock_endpoint(:get, '/products/attributes/1/terms?search=Black%20%26%20White&page=1&per_page=100', [
+      { 'id' => 14, 'name' => 'Black &amp; White' }
+    ])
+
+    response = tester.execute_action('create_or_update_product_attribute_term', {
+      'attributeId' => 1,
+      'name' => 'Black & White',
+      'ensure_exists' => true
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(14)
+    expect(data['operation']).to eq('existing')
+  end
+
+  it 'creates the term when ensuring a term that does not exist' do
+    mock_server.mock_endpoint(:get, '/products/attributes/1/terms?search=Gr%C3%B6n&page=1&per_page=100', [])
+    mock_server.mock_endpoint(:post, '/products/attributes/1/terms', { 'id' => 13, 'name' => 'Grön' }, status: 201)
+
+    response = tester.execute_action('create_or_update_product_attribute_term', {
+      'attributeId' => 1,
+      'name' => 'Grön',
+      'ensure_exists' => true
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(13)
+    expect(data['operation']).to eq('created')
+  end
+
+  it 'updates a term (PUT) when termId is provided' do
+    mock_server.mock_endpoint(:put, '/products/attributes/1/terms/10', { 'id' => 10, 'name' => 'Mörkröd' })
+
+    response = tester.execute_action('create_or_update_product_attribute_term', {
+      'attributeId' => 1,
+      'termId' => 10,
+      'name' => 'Mörkröd'
+    })
+
+    expect(JSON.parse(response.serialized_output)['operation']).to eq('updated')
+  end
+end
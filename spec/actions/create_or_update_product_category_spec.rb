@@ -0,0 +1,48 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.create_or_update_product_category' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'creates a category (POST) when categoryId is missing' do
+    mock_server.mock_endpoint(:post, '/products/categories', { 'id' => 9, 'name' => 'Skjortor', 'parent' => 6 }, status: 201)
+
+    response = tester.execute_action('create_or_update_product_category', { 'name' => 'Skjortor', 'parent' => 6 })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(9)
+  end
+
+  it 'updates a category (PUT) when categoryId is provided' do
+    mock_server.mock_endpoint(:put, '/products/categories/9', { 'id' => 9, 'name' => 'Skjortor & blusar' })
+
+    response = tester.execute_action('create_or_update_product_category', { 'categoryId' => 9, 'name' => 'Skjortor & blusar' })
+
+    expect(JSON.parse(response.serialized_output)['name']).to eq('Skjortor & blusar')
+  end
+end
@@ -0,0 +1,65 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.create_or_update_product_review' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'creates an imported review marked as verified' do
+    mock_server.mock_endpoint(:post, '/products/reviews', {
+      'id' => 101, 'product_id' => 10, 'verified' => true, 'status' => 'approved'
+    }, status: 201)
+
+    response = tester.execute_action('create_or_update_product_review', {
+      'product_id' => 10,
+      'review' => 'Skön och håller formen.',
+      'reviewer' => 'Anna Andersson',
+      'reviewer_email' => 'anna@example.com',
+      'rating' => 5,
+      'verified' => true
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(101)
+    expect(data['verified']).to eq(true)
+  end
+
+  it 'updates a review (PUT) when reviewId is provided' do
+    mock_server.mock_endpoint(:put, '/products/reviews/101', { 'id' => 101, 'rating' => 4 })
+
+    response = tester.execute_action('create_or_update_product_review', { 'reviewId' => 101, 'rating' => 4 })
+
+    expect(JSON.parse(response.serialized_output)['rating']).to eq(4)
+  end
+
+  it 'raises an error if required fields are missing when creating' do
+    expect {
+      tester.execute_action('create_or_update_product_review', { 'product_id' => 10, 'review' => 'Bra' })
+    }.to raise_error(AppBridge::MisconfiguredError, /reviewer is required/)
+  end
+end
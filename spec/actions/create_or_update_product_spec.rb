@@ -94,4 +94,233 @@ RSpec.describe 'actions.create_or_update_product' do
       tester.execute_action('create_or_update_product', input)
     }.to raise_error(AppBridge::OtherError, /400/)
   end
+
+  it 'updates the existing product when matching by SKU finds a match' do
+    input = { 'match_by' => 'sku', 'sku' => 'TS-001', 'regular_price' => '349' }
+
+    mock_server.mock_endpoint(:get, '/products?sku=TS-001', [
+      { 'id' => 101, 'sku' => 'TS-001', 'type' => 'simple', 'parent_id' => 0 }
+    ])
+    mock_server.mock_endpoint(:put, '/products/101', { 'id' => 101, 'sku' => 'TS-001' }, status: 200)
+
+    response = tester.execute_action('create_or_update_product', input)
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(101)
+    expect(data['operation']).to eq('updated')
+  end
+
+  it 'updates a variation through its parent when the SKU belongs to a variation' do
+    input = { 'match_by' => 'sku', 'sku' => 'TS-001-RED', 'regular_price' => '349' }
+
+    mock_server.mock_endpoint(:get, '/products?sku=TS-001-RED', [
+      { 'id' => 205, 'sku' => 'TS-001-RED', 'type' => 'variation', 'parent_id' => 101 }
+    ])
+    mock_server.mock_endpoint(:put, '/products/101/variations/205', { 'id' => 205 }, status: 200)
+
+    response = tester.execute_action('create_or_update_product', input)
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(205)
+    expect(data['operation']).to eq('updated')
+  end
+
+  it 'creates the product when matching by SKU finds no match' do
+    input = { 'match_by' => 'sku', 'sku' => 'TS-NEW', 'name' => 'Ny T-shirt' }
+
+    mock_server.mock_endpoint(:get, '/products?sku=TS-NEW', [])
+    mock_server.mock_endpoint(:post, '/products', { 'id' => 300, 'sku' => 'TS-NEW' }, status: 201)
+
+    response = tester.execute_action('create_or_update_product', input)
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(300)
+    expect(data['operation']).to eq('created')
+  end
+
+  it 'resolves tag names to existing tags and creates missing ones' do
+    input = { 'name' => 'Linneskjorta', 'tags' => [{ 'id' => 2 }, { 'name' => 'Rea' }, { 'name' => 'Linne' }] }
+
+    mock_server.mock_endpoint(:get, '/products/tags?search=Rea&page=1&per_page=100', [
+      { 'id' => 4, 'name' => 'Sommarrea' },
+      { 'id' => 3, 'name' => 'rea' }
+    ])
+    mock_server.mock_endpoint(:get, '/products/tags?search=Linne&page=1&per_page=100', [])
+    mock_server.mock_endpoint(:post, '/products/tags', { 'id' => 9, 'name' => 'Linne' }, status: 201)
+    mock_server.mock_endpoint(:post, '/products', {
+      'id' => 301,
+      'tags' => [{ 'id' => 2 }, { 'id' => 3 }, { 'id' => 9 }]
+    }, status: 201)
+
+    response = tester.execute_action('create_or_update_product', input)
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['tags'].map { |t| t['id'] }).to eq([2, 3, 9])
+  end
+
+  it 'resolves a shipping class given by name to its slug' do
+    mock_server.mock_endpoint(:get, '/products/shipping_classes?page=1&per_page=100', [
+      { 'id' => 1, 'name' => 'Skrymmande gods', 'slug' => 'skrymmande' }
+    ])
+    mock_server.mock_endpoint(:put, '/products/10', { 'id' => 10, 'shipping_class' => 'skrymmande', 'shipping_class_id' => 1 })
+
+    response = tester.execute_action('create_or_update_product', { 'productId' => 10, 'shipping_class' => 'skrymmande Gods' })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['shipping_class_id']).to eq(1)
+  end
+
+  it 'raises an error for an unknown shipping class' do
+    mock_server.mock_endpoint(:get, '/products/shipping_classes?page=1&per_page=100', [])
+
+    expect {
+      tester.execute_action('create_or_update_product', { 'productId' => 10, 'shipping_class' => 'Pall' })
+    }.to raise_error(AppBridge::MisconfiguredError, /Shipping class 'Pall' not found/)
+  end
+
+  it 'skips the update when nothing changed and skip_unchanged is set' do
+    mock_server.mock_endpoint(:get, '/products/10', {
+      'id' => 10,
+      'name' => 'T-shirt',
+      'regular_price' => '199.00',
+      'categories' => [{ 'id' => 15, 'name' => 'Kläder' }, { 'id' => 16, 'name' => 'Herr' }],
+      'meta_data' => [{ 'id' => 900, 'key' => 'season', 'value' => 'SS26' }]
+    })
+
+    response = tester.execute_action('create_or_update_product', {
+      'productId' => 10,
+      'skip_unchanged' => true,
+      'name' => 'T-shirt',
+      'regular_price' => '199',
+      'categories' => [{ 'id' => 16 }, { 'id' => 15 }],
+      'meta_data' => [{ 'key' => 'season', 'value' => 'SS26' }]
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['operation']).to eq('unchanged')
+    expect(data['changed_fields']).to eq([])
+  end
+
+  it 'treats images given by URL as unchanged once WooCommerce has sideloaded them' do
+    mock_server.mock_endpoint(:get, '/products/10', {
+      'id' => 10,
+      'images' => [
+        { 'id' => 301, 'src' => 'https://butik.example.com/wp-content/uploads/2026/10/tshirt-red.jpg' },
+        { 'id' => 302, 'src' => 'https://butik.example.com/wp-content/uploads/2026/10/tshirt-back-1-scaled.jpg' }
+      ]
+    })
+
+    response = tester.execute_action('create_or_update_product', {
+      'productId' => 10,
+      'skip_unchanged' => true,
+      'images' => [
+        { 'src' => 'https://supplier.example.com/images/tshirt-red.jpg?v=3' },
+        { 'src' => 'https://supplier.example.com/images/tshirt-back.jpg' }
+      ]
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['operation']).to eq('unchanged')
+  end
+
+  it 'reports the changed fields when skip_unchanged is set' do
+    mock_server.mock_endpoint(:get, '/products/10', { 'id' => 10, 'name' => 'T-shirt', 'regular_price' => '199' })
+    mock_server.mock_endpoint(:put, '/products/10', { 'id' => 10, 'name' => 'T-shirt', 'regular_price' => '179' })
+
+    response = tester.execute_action('create_or_update_product', {
+      'productId' => 10,
+      'skip_unchanged' => true,
+      'name' => 'T-shirt',
+      'regular_price' => '179'
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['operation']).to eq('updated')
+    expect(data['changed_fields']).to eq(['regular_price'])
+  end
+
+  it 'skips the update when meta_set only repeats existing values and skip_unchanged is set' do
+    mock_server.mock_endpoint(:get, '/products/10', {
+      'id' => 10,
+      'meta_data' => [{ 'id' => 900, 'key' => 'pim_id', 'value' => '4711' }]
+    })
+
+    response = tester.execute_action('create_or_update_product', {
+      'productId' => 10,
+      'skip_unchanged' => true,
+      'meta_set' => { 'pim_id' => 4711 }
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['operation']).to eq('unchanged')
+  end
+
+  it 'creates an external product with its url and button text' do
+    mock_server.mock_endpoint(:post, '/products', { 'id' => 400, 'type' => 'external' }, status: 201)
+
+    response = tester.execute_action('create_or_update_product', {
+      'name' => 'Cykel hos partner',
+      'type' => 'external',
+      'external_url' => 'https://partner.example.com/cykel',
+      'button_text' => 'Köp hos partner'
+    })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(400)
+  end
+
+  it 'raises an error for an external product without external_url' do
+    expect {
+      tester.execute_action('create_or_update_product', { 'name' => 'Cykel', 'type' => 'external', 'button_text' => 'Köp' })
+    }.to raise_error(AppBridge::MisconfiguredError, /require external_url and button_text/)
+  end
+
+  it 'raises an error for grouped_products on a product that is not grouped' do
+    expect {
+      tester.execute_action('create_or_update_product', { 'name' => 'Paket', 'type' => 'simple', 'grouped_products' => [10, 11] })
+    }.to raise_error(AppBridge::MisconfiguredError, /grouped_products requires type grouped/)
+  end
+
+  it 'raises an error for downloads on a product that is not downloadable' do
+    expect {
+      tester.execute_action('create_or_update_product', {
+        'name' => 'E-bok',
+        'downloads' => [{ 'name' => 'E-bok', 'file' => 'https://example.com/bok.pdf' }]
+      })
+    }.to raise_error(AppBridge::MisconfiguredError, /require downloadable to be true/)
+  end
+
+  it 'raises an error for a price on a variable product' do
+    expect {
+      tester.execute_action('create_or_update_product', { 'name' => 'Hoodie', 'type' => 'variable', 'regular_price' => '499' })
+    }.to raise_error(AppBridge::MisconfiguredError, /no price of their own/)
+  end
+
+  it 'updates external_url on an existing external product without type' do
+    mock_server.mock_endpoint(:get, '/products/400', { 'id' => 400, 'type' => 'external' })
+    mock_server.mock_endpoint(:put, '/products/400', {
+      'id' => 400,
+      'type' => 'external',
+      'external_url' => 'https://partner.example.com/ny-cykel'
+    })
+
+    response = tester.execute_action('create_or_update_product', {
+      'productId' => 400,
+      'external_url' => 'https://partner.example.com/ny-cykel'
+    })
+
+    result = JSON.parse(response.serialized_output)
+    expect(result['external_url']).to eq('https://partner.example.com/ny-cykel')
+    expect(result['operation']).to eq('updated')
+  end
+
+  it 'raises an error for external_url on an existing product that is not external' do
+    mock_server.mock_endpoint(:get, '/products/401', { 'id' => 401, 'type' => 'simple' })
+
+    expect {
+      tester.execute_action('create_or_update_product', {
+        'productId' => 401,
+        'external_url' => 'https://partner.example.com/cykel'
+      })
+    }.to raise_error(AppBridge::MisconfiguredError, /require type external/)
+  end
 end
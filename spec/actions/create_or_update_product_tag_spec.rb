@@ -0,0 +1,48 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.create_or_update_product_tag' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'creates a tag (POST) when tagId is missing' do
+    mock_server.mock_endpoint(:post, '/products/tags', { 'id' => 5, 'name' => 'Nyhet' }, status: 201)
+
+    response = tester.execute_action('create_or_update_product_tag', { 'name' => 'Nyhet' })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(5)
+  end
+
+  it 'updates a tag (PUT) when tagId is provided' do
+    mock_server.mock_endpoint(:put, '/products/tags/5', { 'id' => 5, 'name' => 'Nyheter' })
+
+    response = tester.execute_action('create_or_update_product_tag', { 'tagId' => 5, 'name' => 'Nyheter' })
+
+    expect(JSON.parse(response.serialized_output)['name']).to eq('Nyheter')
+  end
+end
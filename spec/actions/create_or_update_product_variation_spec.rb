@@ -0,0 +1,70 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.create_or_update_product_variation' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'creates a variation (POST) when variationId is missing' do
+    input = {
+      'productId' => 101,
+      'sku' => 'TS-L',
+      'regular_price' => '299',
+      'attributes' => [{ 'id' => 1, 'option' => 'L' }]
+    }
+
+    mock_server.mock_endpoint(:post, '/products/101/variations', { 'id' => 203, 'sku' => 'TS-L' }, status: 201)
+
+    response = tester.execute_action('create_or_update_product_variation', input)
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(203)
+  end
+
+  it 'updates a variation (PUT) when variationId is provided' do
+    input = { 'productId' => 101, 'variationId' => 203, 'stock_quantity' => 5 }
+
+    mock_server.mock_endpoint(:put, '/products/101/variations/203', { 'id' => 203, 'stock_quantity' => 5 })
+
+    response = tester.execute_action('create_or_update_product_variation', input)
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['stock_quantity']).to eq(5)
+  end
+
+  it 'raises an error when WooCommerce returns a 400 Bad Request' do
+    mock_server.mock_endpoint(:post, '/products/101/variations', {
+      'code' => 'product_invalid_sku',
+      'message' => 'Invalid or duplicated SKU.'
+    }, status: 400)
+
+    expect {
+      tester.execute_action('create_or_update_product_variation', { 'productId' => 101, 'sku' => 'TS-S' })
+    }.to raise_error(AppBridge::OtherError, /400/)
+  end
+end
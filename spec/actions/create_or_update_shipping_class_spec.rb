@@ -0,0 +1,48 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.create_or_update_shipping_class' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'creates a shipping class (POST) when shippingClassId is missing' do
+    mock_server.mock_endpoint(:post, '/products/shipping_classes', { 'id' => 3, 'name' => 'Pall', 'slug' => 'pall' }, status: 201)
+
+    response = tester.execute_action('create_or_update_shipping_class', { 'name' => 'Pall', 'slug' => 'pall' })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(3)
+  end
+
+  it 'updates a shipping class (PUT) when shippingClassId is provided' do
+    mock_server.mock_endpoint(:put, '/products/shipping_classes/3', { 'id' => 3, 'name' => 'Helpall' })
+
+    response = tester.execute_action('create_or_update_shipping_class', { 'shippingClassId' => 3, 'name' => 'Helpall' })
+
+    expect(JSON.parse(response.serialized_output)['name']).to eq('Helpall')
+  end
+end
@@ -0,0 +1,98 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.create_variable_product' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'creates a variable product and submits the variations in a batch' do
+    mock_server.mock_endpoint(:post, '/products', { 'id' => 101, 'type' => 'variable' }, status: 201)
+    mock_server.mock_endpoint(:post, '/products/101/variations/batch', {
+      'create' => [
+        { 'id' => 201, 'sku' => 'TS-RED-S' },
+        { 'id' => 202, 'sku' => 'TS-RED-M' },
+        { 'id' => 0, 'error' => { 'code' => 'product_invalid_sku', 'message' => 'Invalid or duplicated SKU.' } },
+        { 'id' => 204, 'sku' => 'TS-BLUE-M' }
+      ]
+    })
+
+    response = tester.execute_action('create_variable_product', {
+      'name' => 'T-shirt',
+      'sku' => 'TS',
+      'regular_price' => '199',
+      'attributes' => [
+        { 'name' => 'Color', 'options' => ['Red', 'Blue'] },
+        { 'name' => 'Size', 'options' => ['S', 'M'] }
+      ],
+      'rules' => [
+        { 'attribute' => 'Size', 'option' => 'M', 'price_adjustment' => 20 }
+      ]
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['product_id']).to eq(101)
+    expect(data['variations'].map { |v| v['id'] }).to eq([201, 202, 204])
+    expect(data['errors'].length).to eq(1)
+    expect(data['errors'].first['sku']).to eq('TS-BLUE-S')
+    expect(data['errors'].first['code']).to eq('product_invalid_sku')
+  end
+
+  it 'returns the product id and reports every variation when the batch request fails' do
+    mock_server.mock_endpoint(:post, '/products', { 'id' => 102, 'type' => 'variable' }, status: 201)
+    mock_server.mock_endpoint(:post, '/products/102/variations/batch', { 'code' => 'internal_server_error' }, status: 500)
+
+    response = tester.execute_action('create_variable_product', {
+      'name' => 'T-shirt',
+      'sku' => 'TS',
+      'attributes' => [{ 'name' => 'Size', 'options' => ['S', 'M'] }]
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['product_id']).to eq(102)
+    expect(data['variations']).to eq([])
+    expect(data['errors'].map { |e| e['sku'] }).to eq(['TS-S', 'TS-M'])
+    expect(data['errors'].first['code']).to eq('batch_request_failed')
+  end
+
+  it 'raises an error when no attributes with options are given' do
+    expect {
+      tester.execute_action('create_variable_product', { 'name' => 'T-shirt', 'attributes' => [] })
+    }.to raise_error(AppBridge::MisconfiguredError, /At least one attribute/)
+  end
+
+  it 'raises an error when WooCommerce rejects the product' do
+    mock_server.mock_endpoint(:post, '/products', { 'code' => 'rest_invalid_param' }, status: 400)
+
+    expect {
+      tester.execute_action('create_variable_product', {
+        'name' => 'T-shirt',
+        'attributes' => [{ 'name' => 'Size', 'options' => ['S'] }]
+      })
+    }.to raise_error(AppBridge::OtherError, /400/)
+  end
+end
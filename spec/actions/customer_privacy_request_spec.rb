@@ -0,0 +1,125 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.customer_privacy_request' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+
+    mock_server.mock_endpoint(:get, '/customers/7', {
+      'id' => 7,
+      'email' => 'kalle@kula.se',
+      'password' => 'secret',
+      'billing' => { 'first_name' => 'Kalle', 'country' => 'SE' }
+    })
+
+    mock_server.mock_endpoint(:get, '/orders?customer=7&page=1&per_page=100', [
+      { 'id' => 70, 'customer_id' => 7, 'total' => '100.00' }
+    ])
+    mock_server.mock_endpoint(:get, '/orders?search=kalle%40kula.se&page=1&per_page=100', [
+      { 'id' => 70, 'customer_id' => 7, 'billing' => { 'email' => 'kalle@kula.se' } },
+      { 'id' => 71, 'customer_id' => 0, 'billing' => { 'email' => 'Kalle@Kula.se' } },
+      { 'id' => 72, 'customer_id' => 0, 'billing' => { 'email' => 'kalle@kula.se.example.com' } }
+    ])
+  end
+
+  it 'exports profile, orders, notes, reviews and downloads' do
+    mock_server.mock_endpoint(:get, '/orders/70/notes', [
+      { 'id' => 1, 'note' => 'Kalle ringde om leveransen' },
+      { 'id' => 2, 'note' => 'Orderstatus ändrad från Behandlas till Slutförd.' }
+    ])
+    mock_server.mock_endpoint(:get, '/orders/71/notes', [])
+    mock_server.mock_endpoint(:get, '/products/reviews?reviewer_email=kalle%40kula.se&status=all&page=1&per_page=100', [
+      { 'id' => 5, 'review' => 'Bra mössa' }
+    ])
+    mock_server.mock_endpoint(:get, '/customers/7/downloads', [])
+
+    response = tester.execute_action('customer_privacy_request', { 'customerId' => 7, 'mode' => 'export' })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['customer']['email']).to eq('kalle@kula.se')
+    expect(data['customer']).not_to have_key('password')
+    expect(data['orders'].map { |o| o['id'] }).to eq([70, 71])
+    expect(data['order_notes'].map { |n| n['id'] }).to eq([1])
+    expect(data['order_notes'].first['order_id']).to eq(70)
+    expect(data['reviews'].first['id']).to eq(5)
+    expect(data['downloads']).to eq([])
+    expect(data['has_more']).to eq(false)
+  end
+
+  it 'anonymizes the customer and their orders' do
+    mock_server.mock_endpoint(:put, '/customers/7', { 'id' => 7, 'email' => 'deleted-7@site.invalid' })
+    mock_server.mock_endpoint(:post, '/orders/batch', { 'update' => [{ 'id' => 70 }, { 'id' => 71 }] })
+
+    response = tester.execute_action('customer_privacy_request', { 'customerId' => 7, 'mode' => 'anonymize' })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['customer']['email']).to eq('deleted-7@site.invalid')
+    expect(data['anonymized_order_ids']).to eq([70, 71])
+    expect(data['errors']).to eq([])
+  end
+
+  it 'reports orders that WooCommerce could not anonymize' do
+    mock_server.mock_endpoint(:put, '/customers/7', { 'id' => 7, 'email' => 'deleted-7@site.invalid' })
+    mock_server.mock_endpoint(:post, '/orders/batch', {
+      'update' => [
+        { 'id' => 70, 'error' => { 'code' => 'woocommerce_rest_shop_order_invalid_id', 'message' => 'Invalid ID.' } },
+        { 'id' => 71 }
+      ]
+    })
+
+    response = tester.execute_action('customer_privacy_request', { 'customerId' => 7, 'mode' => 'anonymize' })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['anonymized_order_ids']).to eq([71])
+    expect(data['errors'].first['order_id']).to eq(70)
+    expect(data['errors'].first['code']).to eq('woocommerce_rest_shop_order_invalid_id')
+  end
+
+  it 'refuses to anonymize when not all orders could be read' do
+    mock_server.mock_endpoint(:get, '/customers/8', { 'id' => 8, 'email' => '' })
+    (1..50).each do |page|
+      orders = Array.new(100) { |i| { 'id' => page * 100 + i, 'customer_id' => 8 } }
+      mock_server.mock_endpoint(:get, "/orders?customer=8&page=#{page}&per_page=100", orders)
+    end
+
+    expect {
+      tester.execute_action('customer_privacy_request', { 'customerId' => 8, 'mode' => 'anonymize' })
+    }.to raise_error(AppBridge::MisconfiguredError, /allow_incomplete/)
+  end
+
+  it 'returns an empty hash when customer is not found and strategy is continue' do
+    mock_server.mock_endpoint(:get, '/customers/999', { 'code' => 'woocommerce_rest_invalid_id' }, status: 404)
+
+    result = tester.execute_action('customer_privacy_request', {
+      'customerId' => 999,
+      'mode' => 'export',
+      'on_not_found' => 'continue'
+    })
+
+    expect(result.serialized_output).to eq('{}')
+  end
+end
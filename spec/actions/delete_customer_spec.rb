@@ -0,0 +1,101 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.delete_customer' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'deletes a customer and returns the deleted customer without password' do
+    mock_server.mock_endpoint(:delete, '/customers/123?force=true', {
+      'id' => 123,
+      'email' => 'kalle@kula.se',
+      'password' => 'secret'
+    })
+
+    response = tester.execute_action('delete_customer', { 'customerId' => 123 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(123)
+    expect(data).not_to have_key('password')
+  end
+
+  it 'reassigns orders to another user before deleting' do
+    mock_server.mock_endpoint(:delete, '/customers/123?force=true&reassign=1', { 'id' => 123 })
+
+    response = tester.execute_action('delete_customer', { 'customerId' => 123, 'reassign' => 1 })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(123)
+  end
+
+  it 'returns an empty hash when customer is not found and strategy is continue' do
+    mock_server.mock_endpoint(:delete, '/customers/999?force=true', {
+      'code' => 'woocommerce_rest_invalid_id',
+      'message' => 'Invalid resource id.',
+      'data' => { 'status' => 400 }
+    }, status: 400)
+
+    result = tester.execute_action('delete_customer', { 'customerId' => 999, 'on_not_found' => 'continue' })
+
+    expect(result.serialized_output).to eq('{}')
+  end
+
+  it 'raises CompleteParentException when customer is not found and strategy is exit_level' do
+    mock_server.mock_endpoint(:delete, '/customers/999?force=true', {
+      'code' => 'woocommerce_rest_invalid_id',
+      'message' => 'Invalid resource id.',
+      'data' => { 'status' => 400 }
+    }, status: 400)
+
+    expect {
+      tester.execute_action('delete_customer', { 'customerId' => 999, 'on_not_found' => 'exit_level' })
+    }.to raise_error(AppBridge::CompleteParentException)
+  end
+
+  it 'raises an error when customer is not found and strategy is fail' do
+    mock_server.mock_endpoint(:delete, '/customers/999?force=true', {
+      'code' => 'woocommerce_rest_invalid_id',
+      'message' => 'Invalid resource id.',
+      'data' => { 'status' => 400 }
+    }, status: 400)
+
+    expect {
+      tester.execute_action('delete_customer', { 'customerId' => 999 })
+    }.to raise_error(AppBridge::OtherError, /Customer not found/)
+  end
+
+  it 'raises an error for other 400 responses even when strategy is continue' do
+    mock_server.mock_endpoint(:delete, '/customers/7?force=true', {
+      'code' => 'rest_invalid_param',
+      'message' => 'Invalid parameter(s): reassign'
+    }, status: 400)
+
+    expect {
+      tester.execute_action('delete_customer', { 'customerId' => 7, 'on_not_found' => 'continue' })
+    }.to raise_error(AppBridge::OtherError, /felkod 400/)
+  end
+end
@@ -0,0 +1,46 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.delete_product_attribute' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'deletes an attribute permanently' do
+    mock_server.mock_endpoint(:delete, '/products/attributes/3?force=true', { 'id' => 3, 'name' => 'Material' })
+
+    response = tester.execute_action('delete_product_attribute', { 'attributeId' => 3 })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(3)
+  end
+
+  it 'raises an error if attributeId is missing' do
+    expect {
+      tester.execute_action('delete_product_attribute', {})
+    }.to raise_error(AppBridge::MisconfiguredError, /attributeId parameter is required/)
+  end
+end
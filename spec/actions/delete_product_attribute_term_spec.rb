@@ -0,0 +1,40 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.delete_product_attribute_term' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'deletes a term permanently' do
+    mock_server.mock_endpoint(:delete, '/products/attributes/1/terms/10?force=true', { 'id' => 10, 'name' => 'Röd' })
+
+    response = tester.execute_action('delete_product_attribute_term', { 'attributeId' => 1, 'termId' => 10 })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(10)
+  end
+end
@@ -0,0 +1,46 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.delete_product_category' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'deletes a category permanently' do
+    mock_server.mock_endpoint(:delete, '/products/categories/9?force=true', { 'id' => 9, 'name' => 'Skjortor' })
+
+    response = tester.execute_action('delete_product_category', { 'categoryId' => 9 })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(9)
+  end
+
+  it 'raises an error if categoryId is missing' do
+    expect {
+      tester.execute_action('delete_product_category', {})
+    }.to raise_error(AppBridge::MisconfiguredError, /categoryId parameter is required/)
+  end
+end
@@ -0,0 +1,51 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.delete_product_variation' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'deletes a variation permanently by default' do
+    mock_server.mock_endpoint(:delete, '/products/101/variations/201?force=true', { 'id' => 201 })
+
+    response = tester.execute_action('delete_product_variation', { 'productId' => 101, 'variationId' => 201 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(201)
+  end
+
+  it 'raises an error when the variation is not found (404)' do
+    mock_server.mock_endpoint(:delete, '/products/101/variations/999?force=true', {
+      'code' => 'woocommerce_rest_product_variation_invalid_id'
+    }, status: 404)
+
+    expect {
+      tester.execute_action('delete_product_variation', { 'productId' => 101, 'variationId' => 999 })
+    }.to raise_error(AppBridge::OtherError, /404/)
+  end
+end
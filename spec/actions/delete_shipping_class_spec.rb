@@ -0,0 +1,46 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.delete_shipping_class' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'deletes a shipping class permanently' do
+    mock_server.mock_endpoint(:delete, '/products/shipping_classes/3?force=true', { 'id' => 3, 'name' => 'Pall' })
+
+    response = tester.execute_action('delete_shipping_class', { 'shippingClassId' => 3 })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(3)
+  end
+
+  it 'raises an error if shippingClassId is missing' do
+    expect {
+      tester.execute_action('delete_shipping_class', {})
+    }.to raise_error(AppBridge::MisconfiguredError, /shippingClassId parameter is required/)
+  end
+end
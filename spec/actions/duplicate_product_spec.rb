@@ -0,0 +1,108 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.duplicate_product' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'copies a variable product and its variations as a draft with rewritten SKUs' do
+    mock_server.mock_endpoint(:get, '/products/10', {
+      'id' => 10,
+      'type' => 'variable',
+      'name' => 'Hoodie SS26',
+      'slug' => 'hoodie-ss26',
+      'sku' => 'HD-SS26',
+      'status' => 'publish',
+      'categories' => [{ 'id' => 15 }],
+      'images' => [{ 'id' => 50, 'src' => 'https://example.com/hoodie.jpg' }],
+      'attributes' => [{ 'id' => 1, 'name' => 'Storlek', 'variation' => true, 'options' => ['S', 'M'] }],
+      'meta_data' => [{ 'id' => 900, 'key' => 'season', 'value' => 'SS26' }],
+      'variations' => [11, 12]
+    })
+    mock_server.mock_endpoint(:get, '/products/10/variations?page=1&per_page=100', [
+      { 'id' => 11, 'sku' => 'HD-SS26-S', 'regular_price' => '499', 'image' => nil, 'attributes' => [{ 'id' => 1, 'option' => 'S' }] },
+      { 'id' => 12, 'sku' => 'HD-SS26-M', 'regular_price' => '499', 'image' => nil, 'attributes' => [{ 'id' => 1, 'option' => 'M' }] }
+    ])
+    mock_server.mock_endpoint(:post, '/products', {
+      'id' => 20, 'name' => 'Hoodie SS27', 'sku' => 'HD-SS27', 'status' => 'draft'
+    }, status: 201)
+    mock_server.mock_endpoint(:post, '/products/20/variations/batch', {
+      'create' => [{ 'id' => 21, 'sku' => 'HD-SS27-S' }, { 'id' => 22, 'sku' => 'HD-SS27-M' }]
+    })
+
+    response = tester.execute_action('duplicate_product', {
+      'productId' => 10,
+      'name' => 'Hoodie SS27',
+      'sku_find' => 'SS26',
+      'sku_replace' => 'SS27'
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['source_id']).to eq(10)
+    expect(data['product_id']).to eq(20)
+    expect(data['product']['status']).to eq('draft')
+    expect(data['variations'].map { |v| v['sku'] }).to eq(['HD-SS27-S', 'HD-SS27-M'])
+    expect(data['errors']).to eq([])
+  end
+
+  it 'copies a simple product without variation requests' do
+    mock_server.mock_endpoint(:get, '/products/30', { 'id' => 30, 'type' => 'simple', 'name' => 'Mugg', 'sku' => 'MUG-1' })
+    mock_server.mock_endpoint(:post, '/products', { 'id' => 31, 'name' => 'Mugg (kopia)', 'sku' => '' }, status: 201)
+
+    response = tester.execute_action('duplicate_product', { 'productId' => 30 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['product']['name']).to eq('Mugg (kopia)')
+    expect(data['variations']).to eq([])
+  end
+
+  it 'returns the copy and reports the variations when the variation batch fails' do
+    mock_server.mock_endpoint(:get, '/products/40', { 'id' => 40, 'type' => 'variable', 'name' => 'Jacka', 'sku' => 'JK' })
+    mock_server.mock_endpoint(:get, '/products/40/variations?page=1&per_page=100', [
+      { 'id' => 41, 'sku' => 'JK-S', 'manage_stock' => 'parent', 'stock_quantity' => 7 }
+    ])
+    mock_server.mock_endpoint(:post, '/products', { 'id' => 45, 'name' => 'Jacka (kopia)' }, status: 201)
+    mock_server.mock_endpoint(:post, '/products/45/variations/batch', { 'code' => 'internal_server_error' }, status: 500)
+
+    response = tester.execute_action('duplicate_product', { 'productId' => 40, 'sku_suffix' => '-27' })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['product_id']).to eq(45)
+    expect(data['variations']).to eq([])
+    expect(data['errors'].first['sku']).to eq('JK-S-27')
+    expect(data['errors'].first['code']).to eq('batch_request_failed')
+  end
+
+  it 'raises an error when asked to duplicate a variation' do
+    mock_server.mock_endpoint(:get, '/products/11', { 'id' => 11, 'type' => 'variation', 'parent_id' => 10 })
+
+    expect {
+      tester.execute_action('duplicate_product', { 'productId' => 11 })
+    }.to raise_error(AppBridge::MisconfiguredError, /refers to a variation/)
+  end
+end
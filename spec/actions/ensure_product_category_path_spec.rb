@@ -0,0 +1,71 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.ensure_product_category_path' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'reuses existing levels and creates the missing ones' do
+    mock_server.mock_endpoint(:get, '/products/categories?parent=0&search=Kl%C3%A4der&page=1&per_page=100', [
+      { 'id' => 5, 'name' => 'Klädervård', 'parent' => 0 },
+      { 'id' => 4, 'name' => 'Kläder', 'slug' => 'klader', 'parent' => 0 }
+    ])
+    mock_server.mock_endpoint(:get, '/products/categories?parent=4&search=Herr&page=1&per_page=100', [
+      { 'id' => 6, 'name' => 'herr', 'slug' => 'herr', 'parent' => 4 }
+    ])
+    mock_server.mock_endpoint(:get, '/products/categories?parent=6&search=Skjortor&page=1&per_page=100', [])
+    mock_server.mock_endpoint(:post, '/products/categories',
+      { 'id' => 9, 'name' => 'Skjortor', 'slug' => 'skjortor', 'parent' => 6 }, status: 201)
+
+    response = tester.execute_action('ensure_product_category_path', { 'path' => 'Kläder > Herr > Skjortor' })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(9)
+    expect(data['categories'].map { |c| c['id'] }).to eq([4, 6, 9])
+    expect(data['categories'].map { |c| c['created'] }).to eq([false, false, true])
+  end
+
+  it 'supports a custom separator' do
+    mock_server.mock_endpoint(:get, '/products/categories?parent=0&search=Kl%C3%A4der&page=1&per_page=100', [
+      { 'id' => 4, 'name' => 'Kläder', 'parent' => 0 }
+    ])
+    mock_server.mock_endpoint(:get, '/products/categories?parent=4&search=Dam&page=1&per_page=100', [
+      { 'id' => 7, 'name' => 'Dam', 'parent' => 4 }
+    ])
+
+    response = tester.execute_action('ensure_product_category_path', { 'path' => 'Kläder/Dam', 'separator' => '/' })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(7)
+  end
+
+  it 'raises an error if the path is empty' do
+    expect {
+      tester.execute_action('ensure_product_category_path', { 'path' => ' > ' })
+    }.to raise_error(AppBridge::MisconfiguredError, /at least one category name/)
+  end
+end
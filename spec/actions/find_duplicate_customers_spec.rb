@@ -0,0 +1,105 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.find_duplicate_customers' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+
+    mock_server.mock_endpoint(:get, '/customers?role=all&orderby=id&page=1&per_page=100', [
+      { 'id' => 1, 'email' => 'kalle@kula.se', 'first_name' => 'Kalle', 'billing' => { 'phone' => '070-123 45 67' } },
+      { 'id' => 2, 'email' => 'Kalle+shop@Kula.se', 'first_name' => 'Kalle', 'billing' => { 'phone' => '+46 70 123 45 67' } },
+      { 'id' => 3, 'email' => 'lisa@example.com', 'first_name' => 'Lisa', 'billing' => { 'phone' => '08-555 000' } }
+    ])
+  end
+
+  it 'clusters customers by plus-addressed email and normalised phone number' do
+    response = tester.execute_action('find_duplicate_customers', {})
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['scanned_customers']).to eq(3)
+    expect(data['has_more']).to eq(false)
+    expect(data['clusters'].length).to eq(1)
+
+    cluster = data['clusters'].first
+    expect(cluster['members'].map { |m| m['customer_id'] }).to eq([1, 2])
+    expect(cluster['reasons']).to contain_exactly('email_plus_addressing', 'phone')
+    expect(cluster['confidence']).to be > 0.9
+  end
+
+  it 'includes guest orders when requested' do
+    mock_server.mock_endpoint(:get, '/orders?customer=0&page=1&per_page=100', [
+      { 'id' => 500, 'billing' => { 'email' => 'LISA@example.com', 'first_name' => 'Lisa' } },
+      { 'id' => 501, 'billing' => { 'email' => 'lisa@example.com', 'first_name' => 'Lisa' } }
+    ])
+
+    response = tester.execute_action('find_duplicate_customers', { 'include_guest_orders' => true })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['scanned_guests']).to eq(1)
+
+    lisa = data['clusters'].find { |c| c['members'].any? { |m| m['customer_id'] == 3 } }
+    expect(lisa['reasons']).to eq(['email'])
+    expect(lisa['members'].last['order_ids']).to eq([500, 501])
+  end
+
+  it 'drops clusters below the minimum confidence' do
+    response = tester.execute_action('find_duplicate_customers', { 'min_confidence' => 0.99 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['clusters']).to eq([])
+  end
+
+  context 'when a weak link chains customers together' do
+    before do
+      mock_server.clear_endpoints
+
+      address = { 'address_1' => 'Storgatan 1', 'postcode' => '111 22' }
+      mock_server.mock_endpoint(:get, '/customers?role=all&orderby=id&page=1&per_page=100', [
+        { 'id' => 10, 'email' => 'anna@kula.se' },
+        { 'id' => 11, 'email' => 'anna@kula.se', 'first_name' => 'Anna', 'last_name' => 'Berg', 'billing' => address },
+        { 'id' => 12, 'email' => 'berg@example.com', 'first_name' => 'Anna', 'last_name' => 'Berg', 'billing' => address }
+      ])
+    end
+
+    it 'scores the cluster on its weakest link' do
+      response = tester.execute_action('find_duplicate_customers', {})
+      cluster = JSON.parse(response.serialized_output)['clusters'].first
+
+      expect(cluster['members'].map { |m| m['customer_id'] }).to eq([10, 11, 12])
+      expect(cluster['confidence']).to eq(0.7)
+      expect(cluster['links'].map { |l| l['confidence'] }).to eq([0.95, 0.7])
+    end
+
+    it 'leaves out members only reached through links below the minimum confidence' do
+      response = tester.execute_action('find_duplicate_customers', { 'min_confidence' => 0.8 })
+      clusters = JSON.parse(response.serialized_output)['clusters']
+
+      expect(clusters.length).to eq(1)
+      expect(clusters.first['members'].map { |m| m['customer_id'] }).to eq([10, 11])
+      expect(clusters.first['confidence']).to eq(0.95)
+    end
+  end
+end
@@ -0,0 +1,90 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.find_product_variation' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  let(:variations) do
+    [
+      { 'id' => 201, 'attributes' => [
+        { 'id' => 1, 'name' => 'Color', 'slug' => 'pa_color', 'option' => 'Red' },
+        { 'id' => 0, 'name' => 'Size', 'option' => 'M' }
+      ] },
+      { 'id' => 202, 'attributes' => [
+        { 'id' => 1, 'name' => 'Color', 'slug' => 'pa_color', 'option' => 'Blue' }
+      ] }
+    ]
+  end
+
+  it 'finds the variation matching the attribute values case-insensitively' do
+    mock_server.mock_endpoint(:get, '/products/101/variations?page=1&per_page=100', variations)
+
+    response = tester.execute_action('find_product_variation', {
+      'productId' => 101,
+      'attributes' => { 'color' => 'red', 'Size' => 'm' }
+    })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(201)
+  end
+
+  it 'matches "any" attributes and attribute slugs' do
+    mock_server.mock_endpoint(:get, '/products/101/variations?page=1&per_page=100', variations)
+
+    response = tester.execute_action('find_product_variation', {
+      'productId' => 101,
+      'attributes' => { 'pa_color' => 'Blue', 'Size' => 'XL' }
+    })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(202)
+  end
+
+  it 'resolves the parent product by SKU' do
+    mock_server.mock_endpoint(:get, '/products?sku=TS', [{ 'id' => 101, 'sku' => 'TS' }])
+    mock_server.mock_endpoint(:get, '/products/101/variations?page=1&per_page=100', variations)
+
+    response = tester.execute_action('find_product_variation', {
+      'sku' => 'TS',
+      'attributes' => { 'Color' => 'Red', 'Size' => 'M' }
+    })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(201)
+  end
+
+  it 'returns an empty hash when no variation matches and strategy is continue' do
+    mock_server.mock_endpoint(:get, '/products/101/variations?page=1&per_page=100', variations)
+
+    result = tester.execute_action('find_product_variation', {
+      'productId' => 101,
+      'attributes' => { 'Color' => 'Green' },
+      'on_not_found' => 'continue'
+    })
+
+    expect(result.serialized_output).to eq('{}')
+  end
+end
@@ -0,0 +1,44 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.list_product_attribute_terms' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'lists the terms of an attribute' do
+    mock_server.mock_endpoint(:get, '/products/attributes/1/terms?page=1&per_page=100', [
+      { 'id' => 10, 'name' => 'Röd' },
+      { 'id' => 11, 'name' => 'Blå' }
+    ])
+
+    response = tester.execute_action('list_product_attribute_terms', { 'attributeId' => 1 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items'].map { |t| t['name'] }).to eq(['Röd', 'Blå'])
+  end
+end
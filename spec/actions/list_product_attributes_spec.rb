@@ -0,0 +1,44 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.list_product_attributes' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'lists all global attributes' do
+    mock_server.mock_endpoint(:get, '/products/attributes', [
+      { 'id' => 1, 'name' => 'Färg', 'slug' => 'pa_farg' },
+      { 'id' => 2, 'name' => 'Storlek', 'slug' => 'pa_storlek' }
+    ])
+
+    response = tester.execute_action('list_product_attributes', {})
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items'].map { |a| a['id'] }).to eq([1, 2])
+  end
+end
@@ -0,0 +1,44 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.list_product_categories' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'lists the direct children of a parent category' do
+    mock_server.mock_endpoint(:get, '/products/categories?parent=5&page=1&per_page=100', [
+      { 'id' => 6, 'name' => 'Herr', 'parent' => 5 },
+      { 'id' => 7, 'name' => 'Dam', 'parent' => 5 }
+    ])
+
+    response = tester.execute_action('list_product_categories', { 'parent' => 5 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items'].map { |c| c['id'] }).to eq([6, 7])
+  end
+end
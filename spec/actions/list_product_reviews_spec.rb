@@ -0,0 +1,43 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.list_product_reviews' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'lists reviews filtered by product and status' do
+    mock_server.mock_endpoint(:get, '/products/reviews?product=10%2C11&status=hold&page=1&per_page=100', [
+      { 'id' => 100, 'product_id' => 10, 'status' => 'hold', 'rating' => 4 }
+    ])
+
+    response = tester.execute_action('list_product_reviews', { 'product' => [10, 11], 'status' => 'hold' })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items'].map { |r| r['id'] }).to eq([100])
+  end
+end
@@ -0,0 +1,44 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.list_product_tags' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'lists tags matching a search' do
+    mock_server.mock_endpoint(:get, '/products/tags?search=rea&page=1&per_page=100', [
+      { 'id' => 3, 'name' => 'Rea' },
+      { 'id' => 4, 'name' => 'Sommarrea' }
+    ])
+
+    response = tester.execute_action('list_product_tags', { 'search' => 'rea' })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items'].map { |t| t['id'] }).to eq([3, 4])
+  end
+end
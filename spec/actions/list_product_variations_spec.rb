@@ -0,0 +1,61 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.list_product_variations' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'lists all variations of a product' do
+    mock_server.mock_endpoint(:get, '/products/101/variations?page=1&per_page=100', [
+      { 'id' => 201, 'sku' => 'TS-S' },
+      { 'id' => 202, 'sku' => 'TS-M' }
+    ])
+
+    response = tester.execute_action('list_product_variations', { 'productId' => 101 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items'].map { |v| v['id'] }).to eq([201, 202])
+  end
+
+  it 'passes filters to WooCommerce' do
+    mock_server.mock_endpoint(:get, '/products/101/variations?stock_status=outofstock&page=1&per_page=100', [
+      { 'id' => 202, 'stock_status' => 'outofstock' }
+    ])
+
+    response = tester.execute_action('list_product_variations', { 'productId' => 101, 'stock_status' => 'outofstock' })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items'].length).to eq(1)
+  end
+
+  it 'raises an error if productId is missing' do
+    expect {
+      tester.execute_action('list_product_variations', {})
+    }.to raise_error(AppBridge::MisconfiguredError, /productId parameter is required/)
+  end
+end
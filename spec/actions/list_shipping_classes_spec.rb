@@ -0,0 +1,44 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.list_shipping_classes' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'lists all shipping classes' do
+    mock_server.mock_endpoint(:get, '/products/shipping_classes?page=1&per_page=100', [
+      { 'id' => 1, 'name' => 'Skrymmande', 'slug' => 'skrymmande' },
+      { 'id' => 2, 'name' => 'Brev', 'slug' => 'brev' }
+    ])
+
+    response = tester.execute_action('list_shipping_classes', {})
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items'].map { |c| c['slug'] }).to eq(['skrymmande', 'brev'])
+  end
+end
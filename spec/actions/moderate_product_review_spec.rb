@@ -0,0 +1,64 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.moderate_product_review' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'approves a review' do
+    mock_server.mock_endpoint(:put, '/products/reviews/100', { 'id' => 100, 'status' => 'approved' })
+
+    response = tester.execute_action('moderate_product_review', { 'reviewId' => 100, 'moderation' => 'approve' })
+
+    expect(JSON.parse(response.serialized_output)['status']).to eq('approved')
+  end
+
+  it 'moves a review to the trash' do
+    mock_server.mock_endpoint(:delete, '/products/reviews/100', { 'id' => 100, 'status' => 'trash' })
+
+    response = tester.execute_action('moderate_product_review', { 'reviewId' => 100, 'moderation' => 'trash' })
+
+    expect(JSON.parse(response.serialized_output)['status']).to eq('trash')
+  end
+
+  it 'returns the deleted review when deleting permanently' do
+    mock_server.mock_endpoint(:delete, '/products/reviews/100?force=true', {
+      'deleted' => true, 'previous' => { 'id' => 100, 'status' => 'spam' }
+    })
+
+    response = tester.execute_action('moderate_product_review', { 'reviewId' => 100, 'moderation' => 'delete' })
+
+    expect(JSON.parse(response.serialized_output)['id']).to eq(100)
+  end
+
+  it 'raises an error for an unknown moderation' do
+    expect {
+      tester.execute_action('moderate_product_review', { 'reviewId' => 100, 'moderation' => 'publish' })
+    }.to raise_error(AppBridge::MisconfiguredError, /Unknown moderation/)
+  end
+end
@@ -0,0 +1,91 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.reconcile_inventory' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'updates changed SKUs through the batch endpoints and reports unchanged and unknown SKUs' do
+    mock_server.mock_endpoint(:get, '/products?sku=TS-001,TS-001-M,MUG-1,NOPE&page=1&per_page=100', [
+      { 'id' => 10, 'sku' => 'TS-001', 'type' => 'simple', 'parent_id' => 0, 'manage_stock' => true, 'stock_quantity' => 5 },
+      { 'id' => 21, 'sku' => 'TS-001-M', 'type' => 'variation', 'parent_id' => 20, 'manage_stock' => true, 'stock_quantity' => 2 },
+      { 'id' => 30, 'sku' => 'MUG-1', 'type' => 'simple', 'parent_id' => 0, 'manage_stock' => false, 'stock_quantity' => nil }
+    ])
+    mock_server.mock_endpoint(:post, '/products/batch', { 'update' => [{ 'id' => 30, 'stock_quantity' => 12 }] })
+    mock_server.mock_endpoint(:post, '/products/20/variations/batch', { 'update' => [{ 'id' => 21, 'stock_quantity' => 4 }] })
+
+    response = tester.execute_action('reconcile_inventory', {
+      'items' => [
+        { 'sku' => 'TS-001', 'quantity' => 5 },
+        { 'sku' => 'TS-001-M', 'quantity' => 4 },
+        { 'sku' => 'MUG-1', 'quantity' => 12 },
+        { 'sku' => 'NOPE', 'quantity' => 1 }
+      ]
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['updated'].map { |u| u['sku'] }).to eq(['TS-001-M', 'MUG-1'])
+    expect(data['updated'].first['parent_id']).to eq(20)
+    expect(data['unchanged'].map { |u| u['sku'] }).to eq(['TS-001'])
+    expect(data['unknown']).to eq(['NOPE'])
+    expect(data['errors']).to eq([])
+  end
+
+  it 'reports items rejected by the batch endpoint as errors' do
+    mock_server.mock_endpoint(:get, '/products?sku=MUG-1&page=1&per_page=100', [
+      { 'id' => 30, 'sku' => 'MUG-1', 'type' => 'simple', 'parent_id' => 0, 'manage_stock' => true, 'stock_quantity' => 3 }
+    ])
+    mock_server.mock_endpoint(:post, '/products/batch', {
+      'update' => [{ 'id' => 30, 'error' => { 'code' => 'woocommerce_rest_product_invalid_id', 'message' => 'Invalid ID.' } }]
+    })
+
+    response = tester.execute_action('reconcile_inventory', { 'items' => [{ 'sku' => 'MUG-1', 'quantity' => 7 }] })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['updated']).to eq([])
+    expect(data['errors'].first['sku']).to eq('MUG-1')
+  end
+
+  it 'reports variations whose stock is managed by the parent without updating them' do
+    mock_server.mock_endpoint(:get, '/products?sku=TS-001-L&page=1&per_page=100', [
+      { 'id' => 22, 'sku' => 'TS-001-L', 'type' => 'variation', 'parent_id' => 20, 'manage_stock' => 'parent', 'stock_quantity' => 9 }
+    ])
+
+    response = tester.execute_action('reconcile_inventory', { 'items' => [{ 'sku' => 'TS-001-L', 'quantity' => 3 }] })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['updated']).to eq([])
+    expect(data['parent_managed']).to eq([{ 'sku' => 'TS-001-L', 'id' => 22, 'parent_id' => 20, 'quantity' => 3 }])
+  end
+
+  it 'raises an error if an item has no sku' do
+    expect {
+      tester.execute_action('reconcile_inventory', { 'items' => [{ 'quantity' => 1 }] })
+    }.to raise_error(AppBridge::MisconfiguredError, /needs a sku/)
+  end
+end
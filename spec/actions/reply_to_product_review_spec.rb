@@ -0,0 +1,64 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.reply_to_product_review' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'posts the reply as a child comment of the review' do
+    mock_server.mock_endpoint(:get, '/products/reviews/100', { 'id' => 100, 'product_id' => 10 })
+    mock_server.mock_endpoint(:post, '/wp-json/wp/v2/comments', {
+      'id' => 120, 'post' => 10, 'parent' => 100, 'author_name' => 'Butiken', 'status' => 'approved'
+    }, status: 201)
+
+    response = tester.execute_action('reply_to_product_review', { 'reviewId' => 100, 'reply' => 'Tack för din recension!' })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(120)
+    expect(data['product_id']).to eq(10)
+    expect(data['review_id']).to eq(100)
+  end
+
+  it 'raises a misconfigured error when WordPress rejects the consumer key' do
+    mock_server.mock_endpoint(:get, '/products/reviews/100', { 'id' => 100, 'product_id' => 10 })
+    mock_server.mock_endpoint(:post, '/wp-json/wp/v2/comments', {
+      'code' => 'rest_comment_login_required',
+      'message' => 'Sorry, you must be logged in to comment.'
+    }, status: 401)
+
+    expect {
+      tester.execute_action('reply_to_product_review', { 'reviewId' => 100, 'reply' => 'Tack för din recension!' })
+    }.to raise_error(AppBridge::MisconfiguredError, /application password/)
+  end
+
+  it 'raises an error if the reply is empty' do
+    expect {
+      tester.execute_action('reply_to_product_review', { 'reviewId' => 100, 'reply' => ' ' })
+    }.to raise_error(AppBridge::MisconfiguredError, /reply parameter is required/)
+  end
+end
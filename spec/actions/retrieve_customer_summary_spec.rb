@@ -0,0 +1,86 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.retrieve_customer_summary' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'returns downloads together with an order history summary' do
+    mock_server.mock_endpoint(:get, '/customers/5/downloads', [
+      { 'download_id' => 'abc', 'product_id' => 30, 'download_name' => 'E-bok' }
+    ])
+
+    mock_server.mock_endpoint(:get, '/orders?customer=5&page=1&per_page=100', [
+      {
+        'id' => 2, 'status' => 'completed', 'total' => '300.00', 'currency' => 'SEK',
+        'date_created' => '2024-03-01T10:00:00',
+        'line_items' => [
+          { 'product_id' => 10, 'variation_id' => 0, 'name' => 'Mössa', 'quantity' => 2, 'total' => '200.00' },
+          { 'product_id' => 11, 'variation_id' => 0, 'name' => 'Vantar', 'quantity' => 1, 'total' => '100.00' }
+        ]
+      },
+      {
+        'id' => 1, 'status' => 'processing', 'total' => '150.50', 'currency' => 'SEK',
+        'date_created' => '2023-11-15T08:30:00',
+        'line_items' => [
+          { 'product_id' => 10, 'variation_id' => 0, 'name' => 'Mössa', 'quantity' => 1, 'total' => '150.50' }
+        ]
+      },
+      {
+        'id' => 3, 'status' => 'cancelled', 'total' => '999.00', 'currency' => 'SEK',
+        'date_created' => '2024-04-01T12:00:00',
+        'line_items' => [
+          { 'product_id' => 12, 'variation_id' => 0, 'name' => 'Halsduk', 'quantity' => 9, 'total' => '999.00' }
+        ]
+      }
+    ])
+
+    response = tester.execute_action('retrieve_customer_summary', { 'customerId' => 5 })
+    data = JSON.parse(response.serialized_output)
+    summary = data['order_summary']
+
+    expect(data['downloads'].first['download_id']).to eq('abc')
+    expect(data['has_more']).to eq(false)
+    expect(summary['order_count']).to eq(3)
+    expect(summary['paid_order_count']).to eq(2)
+    expect(summary['total_spent']).to eq('450.50')
+    expect(summary['first_order_date']).to eq('2023-11-15T08:30:00')
+    expect(summary['last_order_date']).to eq('2024-04-01T12:00:00')
+    expect(summary['top_products'].first['product_id']).to eq(10)
+    expect(summary['top_products'].first['quantity']).to eq(3)
+    expect(summary['top_products'].map { |p| p['product_id'] }).not_to include(12)
+  end
+
+  it 'returns an empty hash when customer is not found and strategy is continue' do
+    mock_server.mock_endpoint(:get, '/customers/999/downloads', { 'code' => 'woocommerce_rest_invalid_id' }, status: 404)
+
+    result = tester.execute_action('retrieve_customer_summary', { 'customerId' => 999, 'on_not_found' => 'continue' })
+
+    expect(result.serialized_output).to eq('{}')
+  end
+end
@@ -0,0 +1,88 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.retrieve_product' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'retrieves a product by ID' do
+    mock_server.mock_endpoint(:get, '/products/101', { 'id' => 101, 'name' => 'Mössa' })
+
+    response = tester.execute_action('retrieve_product', { 'productId' => 101 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(101)
+  end
+
+  it 'resolves a variation by SKU' do
+    mock_server.mock_endpoint(:get, '/products?sku=HAT-RED', [
+      { 'id' => 205, 'sku' => 'HAT-RED', 'type' => 'variation', 'parent_id' => 101 }
+    ])
+
+    response = tester.execute_action('retrieve_product', { 'sku' => 'HAT-RED' })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(205)
+    expect(data['parent_id']).to eq(101)
+  end
+
+  it 'ignores results that do not match the requested GTIN' do
+    mock_server.mock_endpoint(:get, '/products?global_unique_id=7310000000001', [
+      { 'id' => 1, 'global_unique_id' => '' }
+    ])
+
+    result = tester.execute_action('retrieve_product', {
+      'global_unique_id' => '7310000000001',
+      'on_not_found' => 'continue'
+    })
+
+    expect(result.serialized_output).to eq('{}')
+  end
+
+  it 'raises CompleteWorkflowException when product is not found and strategy is exit_execution' do
+    mock_server.mock_endpoint(:get, '/products/999', { 'code' => 'woocommerce_rest_product_invalid_id' }, status: 404)
+
+    expect {
+      tester.execute_action('retrieve_product', { 'productId' => 999, 'on_not_found' => 'exit_execution' })
+    }.to raise_error(AppBridge::CompleteWorkflowException)
+  end
+
+  it 'raises an error when the slug is not found and strategy is fail' do
+    mock_server.mock_endpoint(:get, '/products?slug=saknas', [])
+
+    expect {
+      tester.execute_action('retrieve_product', { 'slug' => 'saknas' })
+    }.to raise_error(AppBridge::OtherError, /Product not found/)
+  end
+
+  it 'raises an error when no identifier is given' do
+    expect {
+      tester.execute_action('retrieve_product', {})
+    }.to raise_error(AppBridge::MisconfiguredError, /is required/)
+  end
+end
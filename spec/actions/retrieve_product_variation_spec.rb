@@ -0,0 +1,61 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.retrieve_product_variation' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'retrieves a variation' do
+    mock_server.mock_endpoint(:get, '/products/101/variations/201', { 'id' => 201, 'parent_id' => 101 })
+
+    response = tester.execute_action('retrieve_product_variation', { 'productId' => 101, 'variationId' => 201 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['id']).to eq(201)
+  end
+
+  it 'returns an empty hash when variation is not found and strategy is continue' do
+    mock_server.mock_endpoint(:get, '/products/101/variations/999', { 'code' => 'woocommerce_rest_product_variation_invalid_id' }, status: 404)
+
+    result = tester.execute_action('retrieve_product_variation', {
+      'productId' => 101,
+      'variationId' => 999,
+      'on_not_found' => 'continue'
+    })
+
+    expect(result.serialized_output).to eq('{}')
+  end
+
+  it 'raises an error when variation is not found and strategy is fail' do
+    mock_server.mock_endpoint(:get, '/products/101/variations/999', { 'code' => 'woocommerce_rest_product_variation_invalid_id' }, status: 404)
+
+    expect {
+      tester.execute_action('retrieve_product_variation', { 'productId' => 101, 'variationId' => 999 })
+    }.to raise_error(AppBridge::OtherError, /Variation not found \(404\)/)
+  end
+end
@@ -0,0 +1,82 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.search_customers' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'finds a customer by email and strips password and links' do
+    url = "/customers?page=1&per_page=100&email=kalle%40kula.se"
+
+    mock_server.mock_endpoint(:get, url, [
+      { 'id' => 12, 'email' => 'kalle@kula.se', 'password' => 'secret', '_links' => { 'self' => [] } }
+    ])
+
+    response = tester.execute_action('search_customers', { 'email' => 'kalle@kula.se' })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items'].length).to eq(1)
+    expect(data['items'].first['id']).to eq(12)
+    expect(data['items'].first).not_to have_key('password')
+    expect(data['items'].first).not_to have_key('_links')
+    expect(data['has_more']).to eq(false)
+  end
+
+  it 'passes role and ordering to WooCommerce' do
+    url = "/customers?page=1&per_page=100&role=all&orderby=registered_date&order=desc"
+    mock_server.mock_endpoint(:get, url, [{ 'id' => 1 }, { 'id' => 2 }])
+
+    response = tester.execute_action('search_customers', {
+      'role' => 'all',
+      'orderby' => 'registered_date',
+      'order' => 'desc'
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items'].map { |c| c['id'] }).to eq([1, 2])
+  end
+
+  it 'stops at max_pages and reports that more results exist' do
+    mock_server.mock_endpoint(:get, "/customers?page=1&per_page=2", [{ 'id' => 1 }, { 'id' => 2 }])
+    mock_server.mock_endpoint(:get, "/customers?page=2&per_page=2", [{ 'id' => 3 }, { 'id' => 4 }])
+
+    response = tester.execute_action('search_customers', { 'per_page' => 2, 'max_pages' => 2 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items'].length).to eq(4)
+    expect(data['has_more']).to eq(true)
+  end
+
+  it 'returns an empty items list when no customers match' do
+    mock_server.mock_endpoint(:get, "/customers?page=1&per_page=100&search=nobody", [])
+
+    response = tester.execute_action('search_customers', { 'search' => 'nobody' })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['items']).to eq([])
+  end
+end
@@ -0,0 +1,81 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.send_order_email' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'sends the customer invoice by default' do
+    mock_server.mock_endpoint(:post, '/orders/123/actions/send_email', {
+      'message' => 'Billing email sent to kalle@kula.se.'
+    })
+
+    response = tester.execute_action('send_order_email', { 'orderId' => 123 })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['order_id']).to eq(123)
+    expect(data['template_id']).to eq('customer_invoice')
+    expect(data['message']).to eq('Billing email sent to kalle@kula.se.')
+  end
+
+  it 'uses the send_order_details endpoint for order details' do
+    mock_server.mock_endpoint(:post, '/orders/123/actions/send_order_details', {
+      'message' => 'Order details sent to kalle@kula.se, via REST API.'
+    })
+
+    response = tester.execute_action('send_order_email', {
+      'orderId' => 123,
+      'template_id' => 'order_details'
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['template_id']).to eq('order_details')
+  end
+
+  it 'raises UnsupportedError when the store lacks the order actions endpoint' do
+    mock_server.mock_endpoint(:post, '/orders/123/actions/send_email', {
+      'code' => 'rest_no_route',
+      'message' => 'No route was found matching the URL and request method.'
+    }, status: 404)
+
+    expect {
+      tester.execute_action('send_order_email', { 'orderId' => 123 })
+    }.to raise_error(AppBridge::UnsupportedError)
+  end
+
+  it 'raises an error when the order does not exist' do
+    mock_server.mock_endpoint(:post, '/orders/999/actions/send_email', {
+      'code' => 'woocommerce_rest_order_invalid_id',
+      'message' => 'Invalid order ID.'
+    }, status: 404)
+
+    expect {
+      tester.execute_action('send_order_email', { 'orderId' => 999 })
+    }.to raise_error(AppBridge::OtherError, /404/)
+  end
+end
@@ -0,0 +1,85 @@
+require 'spec_helper'
+
+RSpec.describe 'actions.update_meta_data' do
+  let(:mock_server) { instance_variable_get(:@mock_server) }
+
+  let(:app) do
+    AppBridge::App.new('target/wasm32-wasip2/release/woocommerce_connector.wasm')
+  end
+
+  let(:connection) do
+    AppBridge::Connection.new(
+      'test-id',
+      'WooCommerce Connection',
+      {
+        'base_url' => 'http://localhost:8080',
+        'headers' => {
+          'Authorization' => 'Basic abc',
+          'Accept' => 'application/json',
+          'Content-Type' => 'application/json'
+        }
+      }.to_json
+    )
+  end
+
+  let(:tester) do
+    TestHelper::ActionTester.new(app, connection)
+  end
+
+  before do
+    mock_server.clear_endpoints
+  end
+
+  it 'sets and deletes order meta keys reusing existing meta ids' do
+    mock_server.mock_endpoint(:get, '/orders/500', {
+      'id' => 500,
+      'meta_data' => [
+        { 'id' => 1, 'key' => 'erp_order_id', 'value' => 'A-1' },
+        { 'id' => 2, 'key' => 'erp_order_id', 'value' => 'A-1' },
+        { 'id' => 3, 'key' => 'tracking', 'value' => 'XYZ' }
+      ]
+    })
+    mock_server.mock_endpoint(:put, '/orders/500', {
+      'id' => 500,
+      'meta_data' => [
+        { 'id' => 1, 'key' => 'erp_order_id', 'value' => 'A-2' },
+        { 'id' => 4, 'key' => 'synced', 'value' => 'yes' }
+      ]
+    })
+
+    response = tester.execute_action('update_meta_data', {
+      'resource' => 'order',
+      'id' => 500,
+      'meta_set' => { 'erp_order_id' => 'A-2', 'synced' => 'yes' },
+      'meta_delete' => ['tracking']
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['changed_keys']).to eq(['erp_order_id', 'synced', 'tracking'])
+    expect(data['meta']).to eq({ 'erp_order_id' => 'A-2', 'synced' => 'yes' })
+  end
+
+  it 'does not write when every key already has the wanted value' do
+    mock_server.mock_endpoint(:get, '/customers/123', {
+      'id' => 123,
+      'meta_data' => [{ 'id' => 70, 'key' => 'erp_id', 'value' => 'C-1' }]
+    })
+
+    response = tester.execute_action('update_meta_data', {
+      'resource' => 'customer',
+      'id' => 123,
+      'meta_set' => { 'erp_id' => 'C-1' },
+      'meta_delete' => ['missing_key']
+    })
+    data = JSON.parse(response.serialized_output)
+
+    expect(data['changed_keys']).to eq([])
+    expect(data['meta']).to eq({ 'erp_id' => 'C-1' })
+  end
+
+  it 'raises an error for an unknown resource' do
+    expect {
+      tester.execute_action('update_meta_data', { 'resource' => 'coupon', 'id' => 1 })
+    }.to raise_error(AppBridge::MisconfiguredError, /Unknown resource/)
+  end
+end
@@ -0,0 +1,182 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{find_product_by, handle_not_found, id_parameter, parse_response, product_endpoint};
+use serde_json::{json, Value};
+
+/// How many times the stock is re-read when it changes between reading and writing
+const MAX_ATTEMPTS: u32 = 3;
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let on_not_found = input_data.get("on_not_found")
+    .and_then(|v| v.as_str())
+    .unwrap_or("fail");
+
+  let delta = input_data.get("delta")
+    .and_then(|v| v.as_i64())
+    .ok_or_else(|| AppError {
+      code: ErrorCode::Misconfigured,
+      message: "delta parameter is required".to_string(),
+    })?;
+
+  let product_id = id_parameter(&input_data, "productId");
+  let variation_id = id_parameter(&input_data, "variationId");
+
+  let (mut endpoint, mut item) = match (product_id, variation_id) {
+    (Some(parent), Some(variation)) => {
+      let endpoint = format!("/products/{}/variations/{}", parent, variation);
+      match fetch(&client, &endpoint)? {
+        Some(item) => (endpoint, item),
+        None => return handle_not_found(on_not_found, "Variation", &endpoint),
+      }
+    }
+    (Some(id), None) => {
+      let endpoint = format!("/products/{}", id);
+      match fetch(&client, &endpoint)? {
+        Some(item) => (product_endpoint(&item), item),
+        None => return handle_not_found(on_not_found, "Product", &endpoint),
+      }
+    }
+    (None, _) => {
+      let sku = input_data.get("sku")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| AppError {
+          code: ErrorCode::Misconfigured,
+          message: "productId or sku is required".to_string(),
+        })?;
+
+      match find_product_by(&client, "sku", sku)? {
+        Some(item) => (product_endpoint(&item), item),
+        None => return handle_not_found(on_not_found, "Product", &format!("/products?sku={}", sku)),
+      }
+    }
+  };
+
+  // A variation with manage_stock "parent" shares the stock of its parent product
+  if item.get("manage_stock").and_then(|v| v.as_str()) == Some("parent") {
+    let parent = parent_id(&item, &endpoint).ok_or_else(|| AppError {
+      code: ErrorCode::MalformedResponse,
+      message: "Variation uses the parent stock but has no parent_id".to_string(),
+    })?;
+    endpoint = format!("/products/{}", parent);
+    item = fetch(&client, &endpoint)?.ok_or_else(|| AppError {
+      code: ErrorCode::Other,
+      message: format!("Parent product not found (404) at {}", endpoint),
+    })?;
+  }
+
+  // The REST API has no conditional writes, so the stock is read again right before writing and
+  // the write starts over from the new quantity if a sale changed it in between
+  let mut attempts = 1;
+  loop {
+    let latest = fetch(&client, &endpoint)?.ok_or_else(|| AppError {
+      code: ErrorCode::Other,
+      message: format!("Product disappeared while adjusting stock at {}", endpoint),
+    })?;
+    if latest.get("stock_quantity") == item.get("stock_quantity") {
+      break;
+    }
+    if attempts == MAX_ATTEMPTS {
+      return Err(AppError {
+        code: ErrorCode::Other,
+        message: format!("Stock at {} kept changing, gave up after {} attempts", endpoint, MAX_ATTEMPTS),
+      });
+    }
+    attempts += 1;
+    item = latest;
+  }
+
+  let manage_stock = item.get("manage_stock").and_then(|v| v.as_bool()).unwrap_or(false);
+  let previous_quantity = item.get("stock_quantity").and_then(|v| v.as_i64()).unwrap_or(0);
+  let expected_quantity = previous_quantity + delta;
+
+  let mut request_body = json!({ "stock_quantity": expected_quantity });
+  if !manage_stock {
+    request_body["manage_stock"] = json!(true);
+  }
+
+  let (status, body) = client.put(&endpoint, &request_body)?;
+  parse_response(status, &body)?;
+
+  // A sale in the short window between the last read and the write can still be overwritten,
+  // re-reading shows whether the stock moved
+  let updated = fetch(&client, &endpoint)?.ok_or_else(|| AppError {
+    code: ErrorCode::Other,
+    message: format!("Product disappeared after updating stock at {}", endpoint),
+  })?;
+  let stock_quantity = updated.get("stock_quantity").and_then(|v| v.as_i64()).unwrap_or(0);
+
+  let mut output = json!({
+    "id": updated.get("id").or(item.get("id")),
+    "sku": updated.get("sku"),
+    "delta": delta,
+    "previous_quantity": previous_quantity,
+    "expected_quantity": expected_quantity,
+    "stock_quantity": stock_quantity,
+    "stock_status": updated.get("stock_status"),
+    "manage_stock_enabled": !manage_stock,
+    "concurrent_change": stock_quantity != expected_quantity
+  });
+  if let Some(parent) = parent_id(&updated, &endpoint) {
+    output["parent_id"] = json!(parent);
+  }
+
+  Ok(output)
+}
+
+fn fetch(client: &ApiClient, endpoint: &str) -> Result<Option<Value>, AppError> {
+  let (status, body) = client.get(endpoint)?;
+  if status == 404 {
+    return Ok(None);
+  }
+  parse_response(status, &body).map(Some)
+}
+
+/// Variation responses do not always carry parent_id, so fall back to the endpoint it was read from
+fn parent_id(item: &Value, endpoint: &str) -> Option<i64> {
+  item.get("parent_id")
+    .and_then(|v| v.as_i64())
+    .filter(|id| *id > 0)
+    .or_else(|| {
+      endpoint.strip_prefix("/products/")
+        .and_then(|rest| rest.split_once("/variations/"))
+        .and_then(|(parent, _)| parent.parse().ok())
+    })
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
@@ -0,0 +1,129 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{parse_response, parse_wordpress_response, required_id_parameter};
+use serde_json::{json, Value};
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let product_id = required_id_parameter(&input_data, "productId")?;
+  let endpoint = format!("/products/{}", product_id);
+
+  let (status, body) = client.get(&endpoint)?;
+  let product = parse_response(status, &body)?;
+
+  // The first image of a product is its featured image, the rest make up the gallery
+  let current: Vec<Value> = product.get("images")
+    .and_then(|v| v.as_array())
+    .map(|images| images.iter().map(|image| json!({ "id": image.get("id") })).collect())
+    .unwrap_or_default();
+
+  let media_client = client.for_namespace("wp/v2");
+
+  let mut images = Vec::new();
+
+  match input_data.get("featured_image").filter(|v| v.is_object()) {
+    Some(featured) => images.push(product_image(&media_client, featured)?),
+    None => images.extend(current.first().cloned()),
+  }
+
+  let gallery: Option<Vec<Value>> = input_data.get("gallery").and_then(|v| v.as_array()).cloned();
+  let append = input_data.get("gallery_mode").and_then(|v| v.as_str()) == Some("append");
+
+  match gallery {
+    Some(gallery) => {
+      if append {
+        images.extend(current.iter().skip(1).cloned());
+      }
+      for image in &gallery {
+        images.push(product_image(&media_client, image)?);
+      }
+    }
+    None => images.extend(current.iter().skip(1).cloned()),
+  }
+
+  let (status, body) = client.put(&endpoint, &json!({ "images": images }))?;
+  parse_response(status, &body)
+}
+
+/// Images from the media library are referenced by id, with alt text and title saved on the attachment.
+/// Images given by URL are sideloaded by WooCommerce, which copies alt and name to the new attachment.
+/// File content can't be uploaded to the media library, the host HTTP interface only carries text bodies.
+fn product_image(media_client: &ApiClient, image: &Value) -> Result<Value, AppError> {
+  if image.get("base64").and_then(|v| v.as_str()).is_some_and(|s| !s.is_empty()) {
+    return Err(AppError {
+      code: ErrorCode::Unsupported,
+      message: "Uploading base64 image content is not supported, the connector's HTTP interface can only send text bodies. Upload the file to the media library and pass its media_id, or pass a src URL that WooCommerce can download".to_string(),
+    });
+  }
+
+  let alt = image.get("alt").and_then(|v| v.as_str()).filter(|s| !s.is_empty());
+  let title = image.get("title").and_then(|v| v.as_str()).filter(|s| !s.is_empty());
+
+  if let Some(media_id) = image.get("media_id").and_then(|v| v.as_i64()) {
+    if alt.is_some() || title.is_some() {
+      let mut media = json!({});
+      if let Some(alt) = alt {
+        media["alt_text"] = json!(alt);
+      }
+      if let Some(title) = title {
+        media["title"] = json!(title);
+      }
+      let (status, body) = media_client.post(&format!("/media/{}", media_id), &media)?;
+      parse_wordpress_response(status, &body)?;
+    }
+    return Ok(json!({ "id": media_id }));
+  }
+
+  let src = image.get("src")
+    .and_then(|v| v.as_str())
+    .filter(|s| !s.is_empty())
+    .ok_or_else(|| AppError {
+      code: ErrorCode::Misconfigured,
+      message: "Each image needs a media_id or a src".to_string(),
+    })?;
+
+  let mut product_image = json!({ "src": src });
+  if let Some(alt) = alt {
+    product_image["alt"] = json!(alt);
+  }
+  if let Some(title) = title {
+    product_image["name"] = json!(title);
+  }
+  Ok(product_image)
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("../../schemas/shared/product_base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
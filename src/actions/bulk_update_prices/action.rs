@@ -0,0 +1,288 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{add_query_parameter, batch_item_error, batch_update, get_all_pages_with_more};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+const MAX_PAGES: u32 = 50;
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let rule = PriceRule::from_input(&input_data)?;
+
+  let preview = input_data.get("preview").and_then(|v| v.as_bool()).unwrap_or(false);
+  let include_variations = input_data.get("include_variations").and_then(|v| v.as_bool()).unwrap_or(true);
+
+  let mut items = Vec::new();
+  let (products, mut has_more) = get_all_pages_with_more(&client, &selection_endpoint(&input_data)?, MAX_PAGES)?;
+  for product in products {
+    if product.get("type").and_then(|v| v.as_str()) == Some("variable") {
+      if include_variations {
+        let id = product.get("id").and_then(|v| v.as_i64()).unwrap_or_default();
+        let (variations, more_variations) = get_all_pages_with_more(&client, &format!("/products/{}/variations", id), MAX_PAGES)?;
+        has_more |= more_variations;
+        items.extend(variations.into_iter().map(|mut variation| {
+          variation["parent_id"] = json!(id);
+          variation["type"] = json!("variation");
+          if variation.get("name").is_none() {
+            variation["name"] = product.get("name").cloned().unwrap_or(Value::Null);
+          }
+          variation
+        }));
+      }
+      continue;
+    }
+    items.push(product);
+  }
+
+  let mut changes = Vec::new();
+  let mut skipped = Vec::new();
+  // Simple products go through /products/batch, variations through their parent
+  let mut batches: BTreeMap<i64, Vec<Value>> = BTreeMap::new();
+
+  for item in &items {
+    let id = item.get("id").and_then(|v| v.as_i64()).unwrap_or_default();
+    let parent_id = if item.get("type").and_then(|v| v.as_str()) == Some("variation") {
+      item.get("parent_id").and_then(|v| v.as_i64()).unwrap_or_default()
+    } else {
+      0
+    };
+
+    let change = match rule.apply(item) {
+      Ok(change) => change,
+      Err(reason) => {
+        skipped.push(json!({ "id": id, "sku": item.get("sku"), "reason": reason }));
+        continue;
+      }
+    };
+
+    let mut update = json!({ "id": id, rule.field: change.new_price });
+    if rule.field == "sale_price" {
+      for date in ["date_on_sale_from", "date_on_sale_to"] {
+        if let Some(value) = input_data.get(date).and_then(|v| v.as_str()).filter(|s| !s.is_empty()) {
+          update[date] = json!(value);
+        }
+      }
+    }
+
+    // Writing the same values again would only touch the modification date
+    if is_unchanged(item, &update) {
+      skipped.push(json!({ "id": id, "sku": item.get("sku"), "reason": format!("Price is already {}", change.new_price) }));
+      continue;
+    }
+    batches.entry(parent_id).or_default().push(update);
+
+    let mut output = json!({
+      "id": id,
+      "sku": item.get("sku"),
+      "name": item.get("name"),
+      "field": rule.field,
+      "regular_price": change.regular_price,
+      "old_price": change.old_price,
+      "new_price": change.new_price
+    });
+    if parent_id > 0 {
+      output["parent_id"] = json!(parent_id);
+    }
+    changes.push(output);
+  }
+
+  let mut errors = Vec::new();
+
+  if !preview {
+    for (parent_id, updates) in batches {
+      let endpoint = if parent_id > 0 {
+        format!("/products/{}/variations/batch", parent_id)
+      } else {
+        "/products/batch".to_string()
+      };
+
+      for (response, update) in batch_update(&client, &endpoint, &updates)?.iter().zip(&updates) {
+        if let Some(mut error) = batch_item_error(response) {
+          error["id"] = update["id"].clone();
+          errors.push(error);
+        }
+      }
+    }
+  }
+
+  Ok(json!({
+    "preview": preview,
+    "items": changes,
+    "skipped": skipped,
+    "errors": errors,
+    "has_more": has_more
+  }))
+}
+
+/// Prices are compared as numbers since WooCommerce may store 199.9 as "199.90"
+fn is_unchanged(item: &Value, update: &Value) -> bool {
+  let Some(fields) = update.as_object() else {
+    return false;
+  };
+
+  fields.iter().filter(|(key, _)| key.as_str() != "id").all(|(key, value)| {
+    let current = item.get(key).and_then(|v| v.as_str()).unwrap_or_default();
+    let wanted = value.as_str().unwrap_or_default();
+    match (current.parse::<f64>(), wanted.parse::<f64>()) {
+      (Ok(current), Ok(wanted)) => current == wanted,
+      _ => current == wanted,
+    }
+  })
+}
+
+fn selection_endpoint(input_data: &Value) -> Result<String, AppError> {
+  let mut query_parts = Vec::new();
+  for param in ["category", "tag", "search"] {
+    add_query_parameter(input_data, param, &mut query_parts);
+  }
+
+  let skus: Vec<String> = input_data.get("skus")
+    .and_then(|v| v.as_array())
+    .map(|skus| {
+      skus.iter()
+        .filter_map(|sku| sku.as_str())
+        .map(|sku| sku.trim())
+        .filter(|sku| !sku.is_empty())
+        .map(|sku| urlencoding::encode(sku).into_owned())
+        .collect()
+    })
+    .unwrap_or_default();
+  if !skus.is_empty() {
+    query_parts.push(format!("sku={}", skus.join(",")));
+  }
+
+  // Without a selection every product in the store would be repriced
+  if query_parts.is_empty() {
+    return Err(AppError {
+      code: ErrorCode::Misconfigured,
+      message: "Select products by category, tag, skus or search".to_string(),
+    });
+  }
+
+  Ok(format!("/products?{}", query_parts.join("&")))
+}
+
+struct PriceChange {
+  regular_price: String,
+  old_price: String,
+  new_price: String,
+}
+
+struct PriceRule {
+  field: &'static str,
+  percentage: bool,
+  adjustment: f64,
+  rounding: String,
+  ending: f64,
+}
+
+impl PriceRule {
+  fn from_input(input_data: &Value) -> Result<Self, AppError> {
+    let adjustment = input_data.get("adjustment").and_then(|v| v.as_f64()).ok_or_else(|| AppError {
+      code: ErrorCode::Misconfigured,
+      message: "adjustment parameter is required".to_string(),
+    })?;
+
+    let field = match input_data.get("apply_to").and_then(|v| v.as_str()).unwrap_or("regular_price") {
+      "sale_price" => "sale_price",
+      _ => "regular_price",
+    };
+
+    let ending = input_data.get("price_ending").and_then(|v| v.as_f64()).unwrap_or(0.9);
+    if !(0.0..1.0).contains(&ending) {
+      return Err(AppError {
+        code: ErrorCode::Misconfigured,
+        message: "price_ending must be at least 0 and below 1".to_string(),
+      });
+    }
+
+    Ok(Self {
+      field,
+      percentage: input_data.get("adjustment_type").and_then(|v| v.as_str()) != Some("fixed"),
+      adjustment,
+      rounding: input_data.get("rounding").and_then(|v| v.as_str()).unwrap_or("none").to_string(),
+      ending,
+    })
+  }
+
+  /// New prices are computed from the current regular price. A sale price can be recalculated without
+  /// drifting, but changing the regular price compounds, running -10 % twice lowers it by 19 %.
+  fn apply(&self, item: &Value) -> Result<PriceChange, String> {
+    let regular_price = item.get("regular_price").and_then(|v| v.as_str()).unwrap_or_default();
+    let base: f64 = regular_price.parse().map_err(|_| "Missing regular price".to_string())?;
+
+    let price = if self.percentage {
+      base * (1.0 + self.adjustment / 100.0)
+    } else {
+      base + self.adjustment
+    };
+    let price = self.round(price);
+
+    if price <= 0.0 {
+      return Err(format!("New price {} is not above zero", format_price(price)));
+    }
+    if self.field == "sale_price" && price >= base {
+      return Err("Sale price is not below the regular price".to_string());
+    }
+
+    Ok(PriceChange {
+      regular_price: regular_price.to_string(),
+      old_price: item.get(self.field).and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+      new_price: format_price(price),
+    })
+  }
+
+  fn round(&self, price: f64) -> f64 {
+    match self.rounding.as_str() {
+      "whole" => price.round(),
+      // Nearest price with the wanted decimals, 203.20 becomes 202.90 and 203.60 becomes 203.90
+      "ending" => (price - self.ending).round().max(0.0) + self.ending,
+      _ => (price * 100.0).round() / 100.0,
+    }
+  }
+}
+
+fn format_price(price: f64) -> String {
+  if price.fract() == 0.0 {
+    format!("{:.0}", price)
+  } else {
+    format!("{:.2}", price)
+  }
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
@@ -1,6 +1,6 @@
 use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
 use crate::client::ApiClient;
-use crate::actions::utils::request_body_without_empty_values;
+use crate::actions::utils::{meta_data_changes, parse_response, request_body_without_empty_values};
 use serde_json::Value;
 
 #[allow(dead_code)]
@@ -26,18 +26,59 @@ pub fn execute(context: ActionContext) -> Result<Value, AppError> {
   let client = client(&context)?;
   let input_data = input_data(&context)?;
 
-  let customer_id = input_data.get("customerId")
+  let match_by = input_data.get("match_by")
+    .and_then(|v| v.as_str())
+    .unwrap_or("id");
+
+  let mut customer_id = input_data.get("customerId")
     .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse::<i64>().ok())));
 
-  let request_body = request_body_without_empty_values(&input_data, &["customerId", "on_not_found"])?;
+  if customer_id.is_none() && match_by == "email" {
+    let email = input_data.get("email")
+      .and_then(|v| v.as_str())
+      .filter(|s| !s.is_empty())
+      .ok_or_else(|| AppError {
+        code: ErrorCode::Misconfigured,
+        message: "email is required when matching customers by email".to_string(),
+      })?;
+
+    customer_id = find_customer_id_by_email(&client, email)?;
+  }
+
+  let mut request_body = request_body_without_empty_values(
+    &input_data,
+    &["customerId", "on_not_found", "match_by", "meta_set", "meta_delete"],
+  )?;
+
+  let meta_set = input_data.get("meta_set");
+  let meta_delete = input_data.get("meta_delete");
+
+  if meta_set.is_some() || meta_delete.is_some() {
+    let current_meta = match customer_id {
+      Some(id) => {
+        let (status, body) = client.get(&format!("/customers/{}", id))?;
+        parse_response(status, &body)?.get("meta_data").cloned().unwrap_or(Value::Null)
+      }
+      None => Value::Null,
+    };
 
-  let (status, response_body) = if let Some(id) = customer_id {
+    let changes = meta_data_changes(&current_meta, meta_set, meta_delete);
+    if !changes.is_empty() {
+      let mut meta_data = request_body.get("meta_data").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+      meta_data.extend(changes);
+      request_body["meta_data"] = Value::Array(meta_data);
+    }
+  }
+
+  let (operation, result) = if let Some(id) = customer_id {
     let endpoint = format!("/customers/{}", id);
-    client.put(&endpoint, &request_body)
+    ("updated", client.put(&endpoint, &request_body))
   } else {
     let endpoint = "/customers";
-    client.post(endpoint, &request_body)
-  }.map_err(|e| AppError {
+    ("created", client.post(endpoint, &request_body))
+  };
+
+  let (status, response_body) = result.map_err(|e| AppError {
     code: ErrorCode::Other,
     message: format!("API-anrop misslyckades: {}", e.message),
   })?;
@@ -49,14 +90,43 @@ pub fn execute(context: ActionContext) -> Result<Value, AppError> {
     });
   }
 
-  let response_json: Value = serde_json::from_str(&response_body).map_err(|e| AppError {
+  let mut response_json: Value = serde_json::from_str(&response_body).map_err(|e| AppError {
     code: ErrorCode::MalformedResponse,
     message: format!("Misslyckades att tolka JSON-svar: {}", e),
   })?;
 
+  if let Some(obj) = response_json.as_object_mut() {
+    obj.insert("operation".to_string(), Value::String(operation.to_string()));
+  }
+
   Ok(response_json)
 }
 
+/// Look up an existing customer by email across all roles
+fn find_customer_id_by_email(client: &ApiClient, email: &str) -> Result<Option<i64>, AppError> {
+  let endpoint = format!("/customers?email={}&role=all", urlencoding::encode(email));
+  let (status, body) = client.get(&endpoint)?;
+
+  if status >= 400 {
+    return Err(AppError {
+      code: ErrorCode::Other,
+      message: format!("WooCommerce returnerade felkod {}: {}", status, body),
+    });
+  }
+
+  let customers: Vec<Value> = serde_json::from_str(&body).map_err(|e| AppError {
+    code: ErrorCode::MalformedResponse,
+    message: format!("Misslyckades att tolka JSON-svar: {}", e),
+  })?;
+
+  // The email filter isn't guaranteed to be exact, so only a customer with the same address counts
+  let wanted = email.trim().to_lowercase();
+  Ok(customers.iter()
+    .find(|c| c.get("email").and_then(|v| v.as_str()).is_some_and(|e| e.trim().to_lowercase() == wanted))
+    .and_then(|c| c.get("id"))
+    .and_then(|v| v.as_i64()))
+}
+
 #[allow(dead_code)]
 pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
   let base_schema = include_str!("base_input_schema.json");
@@ -1,6 +1,7 @@
 use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
 use crate::client::ApiClient;
-use serde_json::Value;
+use crate::actions::utils::{find_product_by, meta_data_changes, parse_response, product_endpoint, resolve_shipping_class, resolve_tag_names};
+use serde_json::{Value, json};
 
 #[allow(dead_code)]
 fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
@@ -36,13 +37,89 @@ pub fn execute(context: ActionContext) -> Result<Value, AppError> {
 
   let product_id = body_map.remove("productId").and_then(|v| v.as_i64());
 
-  let request_body = Value::Object(body_map);
+  let match_by = body_map.remove("match_by");
+  let match_by = match_by.as_ref().and_then(|v| v.as_str()).unwrap_or("id");
 
-  let (status, response_body) = if let Some(id) = product_id {
-    client.put(&format!("/products/{}", id), &request_body)
+  let skip_unchanged = body_map.remove("skip_unchanged")
+    .and_then(|v| v.as_bool())
+    .unwrap_or(false);
+
+  let meta_set = body_map.remove("meta_set");
+  let meta_delete = body_map.remove("meta_delete");
+  let edits_meta = meta_set.is_some() || meta_delete.is_some();
+
+  let mut endpoint = product_id.map(|id| format!("/products/{}", id));
+  let mut current: Option<Value> = None;
+
+  if endpoint.is_none() && match_by == "sku" {
+    let sku = body_map.get("sku")
+      .and_then(|v| v.as_str())
+      .filter(|s| !s.is_empty())
+      .ok_or_else(|| AppError {
+        code: ErrorCode::Misconfigured,
+        message: "sku is required when matching products by SKU".to_string(),
+      })?;
+
+    if let Some(product) = find_product_by(&client, "sku", sku)? {
+      endpoint = Some(product_endpoint(&product));
+      current = Some(product);
+    }
+  }
+
+  // Type specific fields sent without type are checked against the stored product type
+  let needs_type = (!body_map.contains_key("type")
+      && ["external_url", "button_text", "grouped_products"].iter().any(|field| body_map.contains_key(*field)))
+    || (!body_map.contains_key("downloadable")
+      && ["downloads", "download_limit", "download_expiry"].iter().any(|field| body_map.contains_key(*field)));
+
+  if (skip_unchanged || edits_meta || needs_type) && current.is_none() && let Some(endpoint) = &endpoint {
+    let (status, body) = client.get(endpoint)?;
+    current = Some(parse_response(status, &body)?);
+  }
+
+  validate_type_fields(&body_map, current.as_ref(), endpoint.is_none())?;
+
+  let mut request_body = Value::Object(body_map);
+  resolve_tag_names(&client, &mut request_body)?;
+  resolve_shipping_class(&client, &mut request_body)?;
+
+  if edits_meta {
+    let current_meta = current.as_ref().and_then(|product| product.get("meta_data")).unwrap_or(&Value::Null);
+    let changes = meta_data_changes(current_meta, meta_set.as_ref(), meta_delete.as_ref());
+
+    if !changes.is_empty() {
+      let mut meta_data = request_body.get("meta_data").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+      meta_data.extend(changes);
+      request_body["meta_data"] = Value::Array(meta_data);
+    }
+  }
+
+  let mut changed_fields: Vec<String> = request_body.as_object()
+    .map(|body| body.keys().cloned().collect())
+    .unwrap_or_default();
+
+  if skip_unchanged && let Some(current) = current {
+    request_body = changed_values(&request_body, &current);
+    changed_fields = request_body.as_object()
+      .map(|body| body.keys().cloned().collect())
+      .unwrap_or_default();
+
+    // Nothing to write, leave date_modified untouched
+    if changed_fields.is_empty() {
+      let mut product = current;
+      product["operation"] = json!("unchanged");
+      product["changed_fields"] = json!(changed_fields);
+      return Ok(product);
+    }
+  }
+
+  let (operation, result) = if let Some(endpoint) = endpoint {
+    ("updated", client.put(&endpoint, &request_body))
   } else {
-    client.post("/products", &request_body)
-  }.map_err(|e| AppError {
+    ("created", client.post("/products", &request_body))
+  };
+
+  let (status, response_body) = result.map_err(|e| AppError {
     code: ErrorCode::Other,
     message: format!("API request failed: {}", e.message),
   })?;
@@ -54,14 +131,217 @@ pub fn execute(context: ActionContext) -> Result<Value, AppError> {
     });
   }
 
-  let response_json: Value = serde_json::from_str(&response_body).map_err(|e| AppError {
+  let mut response_json: Value = serde_json::from_str(&response_body).map_err(|e| AppError {
     code: ErrorCode::MalformedResponse,
     message: format!("Kunde inte tolka svar från API: {}", e),
   })?;
 
+  if let Some(obj) = response_json.as_object_mut() {
+    obj.insert("operation".to_string(), Value::String(operation.to_string()));
+    if skip_unchanged {
+      obj.insert("changed_fields".to_string(), json!(changed_fields));
+    }
+  }
+
   Ok(response_json)
 }
 
+/// Catch field combinations that WooCommerce would reject or silently ignore for the product type,
+/// mirroring the conditionals in the input schema. Updates that leave out `type` or `downloadable`
+/// are checked against the current product, new products against WooCommerce's defaults.
+fn validate_type_fields(
+  body: &serde_json::Map<String, Value>,
+  current: Option<&Value>,
+  creating: bool,
+) -> Result<(), AppError> {
+  let misconfigured = |message: &str| Err(AppError {
+    code: ErrorCode::Misconfigured,
+    message: message.to_string(),
+  });
+  let given = |field: &str| body.get(field).is_some_and(|v| match v {
+    Value::Null => false,
+    Value::String(s) => !s.is_empty(),
+    Value::Array(a) => !a.is_empty(),
+    _ => true,
+  });
+  let stored = |field: &str| current.and_then(|product| product.get(field));
+
+  let input_type = body.get("type").and_then(|v| v.as_str()).filter(|s| !s.is_empty());
+  let product_type = input_type
+    .or_else(|| stored("type").and_then(|v| v.as_str()))
+    .or(if creating { Some("simple") } else { None });
+  let downloadable = body.get("downloadable").and_then(|v| v.as_bool())
+    .or_else(|| stored("downloadable").and_then(|v| v.as_bool()))
+    .or(if creating { Some(false) } else { None });
+
+  if let Some(other) = input_type.filter(|t| !matches!(*t, "simple" | "variable" | "grouped" | "external")) {
+    return misconfigured(&format!("Unknown product type '{}', expected simple, variable, grouped or external", other));
+  }
+
+  if input_type == Some("external") && (!given("external_url") || !given("button_text")) {
+    return misconfigured("External products require external_url and button_text");
+  }
+
+  if product_type == Some("external")
+    && (body.get("manage_stock").and_then(|v| v.as_bool()) == Some(true) || given("stock_quantity")) {
+    return misconfigured("External products cannot manage stock");
+  }
+
+  if product_type.is_some_and(|t| t != "external") && (given("external_url") || given("button_text")) {
+    return misconfigured("external_url and button_text require type external");
+  }
+
+  if product_type.is_some_and(|t| t != "grouped") && given("grouped_products") {
+    return misconfigured("grouped_products requires type grouped");
+  }
+
+  if matches!(product_type, Some("variable" | "grouped")) && (given("regular_price") || given("sale_price")) {
+    return misconfigured("Variable and grouped products have no price of their own, set prices on the variations or the grouped products");
+  }
+
+  if downloadable == Some(false) && ["downloads", "download_limit", "download_expiry"].iter().any(|field| given(field)) {
+    return misconfigured("downloads, download_limit and download_expiry require downloadable to be true");
+  }
+
+  Ok(())
+}
+
+/// Keep only the input fields whose value differs from the current product
+fn changed_values(input: &Value, current: &Value) -> Value {
+  let mut changed = serde_json::Map::new();
+
+  for (key, value) in input.as_object().into_iter().flatten() {
+    let existing = current.get(key).unwrap_or(&Value::Null);
+
+    if key == "meta_data" {
+      // Meta data is merged by key, so only entries with a new value need to be sent
+      let entries: Vec<Value> = value.as_array()
+        .into_iter()
+        .flatten()
+        .filter(|entry| !meta_entry_exists(entry, existing))
+        .cloned()
+        .collect();
+      if !entries.is_empty() {
+        changed.insert(key.clone(), Value::Array(entries));
+      }
+      continue;
+    }
+
+    // Image order decides the featured image, and images given by URL that are already attached
+    // are compared and sent by id so they aren't sideloaded again
+    if key == "images" {
+      let images = attached_images(value, existing);
+      if !matches(&images, existing, true) {
+        changed.insert(key.clone(), images);
+      }
+      continue;
+    }
+
+    // Other lists are compared as sets
+    if !matches(value, existing, false) {
+      changed.insert(key.clone(), value.clone());
+    }
+  }
+
+  Value::Object(changed)
+}
+
+/// WooCommerce stores a sideloaded image under its own URL, so an image given only by `src` is
+/// replaced with the id of the attached image that was sideloaded from the same file
+fn attached_images(input: &Value, current: &Value) -> Value {
+  let current = current.as_array().cloned().unwrap_or_default();
+
+  let images = input.as_array().into_iter().flatten().map(|image| {
+    let src = image.get("src").and_then(|v| v.as_str()).filter(|_| image.get("id").is_none());
+    let attached = src.and_then(|src| current.iter().find(|stored| {
+      stored.get("src").and_then(|v| v.as_str()).is_some_and(|stored_src| sideloaded_from(stored_src, src))
+    }));
+
+    match (image.as_object(), attached.and_then(|stored| stored.get("id"))) {
+      (Some(fields), Some(id)) => {
+        let mut image = fields.clone();
+        image.remove("src");
+        image.insert("id".to_string(), id.clone());
+        Value::Object(image)
+      }
+      _ => image.clone(),
+    }
+  });
+
+  Value::Array(images.collect())
+}
+
+/// WordPress keeps the file name when sideloading, adding `-1`, `-2` ... when the name is taken
+/// and `-scaled` for large images
+fn sideloaded_from(stored_src: &str, source_src: &str) -> bool {
+  let stem = |url: &str| {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let file = path.rsplit('/').next().unwrap_or_default().to_lowercase();
+    match file.rsplit_once('.') {
+      Some((stem, _extension)) => stem.to_string(),
+      None => file,
+    }
+  };
+
+  let source = stem(source_src);
+  let stored = stem(stored_src);
+  let stored = stored.strip_suffix("-scaled").unwrap_or(&stored);
+
+  !source.is_empty() && (stored == source || stored.strip_prefix(source.as_str()).is_some_and(|rest| {
+    rest.strip_prefix('-').is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+  }))
+}
+
+/// Does the current value contain the input value? Objects only compare the fields given in the
+/// input, so `{ "id": 15 }` matches a category returned with name and slug.
+fn matches(input: &Value, current: &Value, ordered: bool) -> bool {
+  match (input, current) {
+    (Value::Object(input), Value::Object(current)) => input.iter()
+      .all(|(key, value)| matches(value, current.get(key).unwrap_or(&Value::Null), false)),
+    (Value::Array(input), Value::Array(current)) => {
+      input.len() == current.len() && if ordered {
+        input.iter().zip(current).all(|(a, b)| matches(a, b, false))
+      } else {
+        input.iter().all(|a| current.iter().any(|b| matches(a, b, false)))
+      }
+    }
+    (input, current) => scalar_text(input) == scalar_text(current),
+  }
+}
+
+/// WooCommerce returns prices and dimensions as strings, so `199`, `"199"` and `"199.00"` are equal
+/// and an empty string equals a missing value
+fn scalar_text(value: &Value) -> Option<String> {
+  let text = match value {
+    Value::Null => return None,
+    Value::String(s) if s.is_empty() => return None,
+    Value::String(s) => s.clone(),
+    other => other.to_string(),
+  };
+
+  // Only trailing decimal zeros are dropped, leading zeros matter in SKUs
+  let is_decimal = text.split_once('.').is_some_and(|(whole, fraction)| {
+    !whole.is_empty() && !fraction.is_empty()
+      && whole.trim_start_matches('-').chars().all(|c| c.is_ascii_digit())
+      && fraction.chars().all(|c| c.is_ascii_digit())
+  });
+
+  if is_decimal {
+    Some(text.trim_end_matches('0').trim_end_matches('.').to_string())
+  } else {
+    Some(text)
+  }
+}
+
+fn meta_entry_exists(entry: &Value, current_meta: &Value) -> bool {
+  let key = entry.get("key");
+  let value = entry.get("value").unwrap_or(&Value::Null);
+
+  current_meta.as_array().into_iter().flatten().any(|existing| {
+    existing.get("key") == key && matches(value, existing.get("value").unwrap_or(&Value::Null), true)
+  })
+}
+
 #[allow(dead_code)]
 pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
   let base_schema = include_str!("base_input_schema.json");
@@ -72,6 +352,18 @@ pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppEr
 #[allow(dead_code)]
 pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
   let base_schema = include_str!("../../schemas/shared/product_base_output_schema.json");
-  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  let mut schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  schema["properties"]["operation"] = json!({
+    "title": "Utförd åtgärd",
+    "description": "Om produkten skapades, uppdaterades eller redan var aktuell.",
+    "type": "string",
+    "enum": ["created", "updated", "unchanged"]
+  });
+  schema["properties"]["changed_fields"] = json!({
+    "title": "Ändrade fält",
+    "description": "Fälten som skickades till WooCommerce när endast ändrade fält uppdateras.",
+    "type": "array",
+    "items": { "type": "string" }
+  });
   Ok(schema)
 }
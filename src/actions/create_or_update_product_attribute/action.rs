@@ -0,0 +1,54 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{id_parameter, parse_response, request_body_without_empty_values};
+use serde_json::Value;
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let attribute_id = id_parameter(&input_data, "attributeId");
+  let request_body = request_body_without_empty_values(&input_data, &["attributeId"])?;
+
+  let (status, response_body) = if let Some(id) = attribute_id {
+    client.put(&format!("/products/attributes/{}", id), &request_body)
+  } else {
+    client.post("/products/attributes", &request_body)
+  }?;
+
+  parse_response(status, &response_body)
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("../../schemas/shared/product_attribute_base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
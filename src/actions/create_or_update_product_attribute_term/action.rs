@@ -0,0 +1,102 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{get_all_pages, id_parameter, parse_response, request_body_without_empty_values, required_id_parameter, term_name_matches};
+use serde_json::{json, Value};
+
+const MAX_PAGES: u32 = 20;
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let attribute_id = required_id_parameter(&input_data, "attributeId")?;
+  let term_id = id_parameter(&input_data, "termId");
+
+  let ensure_exists = input_data.get("ensure_exists")
+    .and_then(|v| v.as_bool())
+    .unwrap_or(false);
+
+  let terms_endpoint = format!("/products/attributes/{}/terms", attribute_id);
+
+  if term_id.is_none() && ensure_exists {
+    let name = input_data.get("name")
+      .and_then(|v| v.as_str())
+      .filter(|s| !s.trim().is_empty())
+      .ok_or_else(|| AppError {
+        code: ErrorCode::Misconfigured,
+        message: "name is required when ensuring that a term exists".to_string(),
+      })?;
+
+    if let Some(mut term) = find_term_by_name(&client, &terms_endpoint, name)? {
+      term["operation"] = json!("existing");
+      return Ok(term);
+    }
+  }
+
+  let request_body = request_body_without_empty_values(&input_data, &["attributeId", "termId", "ensure_exists"])?;
+
+  let (operation, result) = if let Some(id) = term_id {
+    ("updated", client.put(&format!("{}/{}", terms_endpoint, id), &request_body))
+  } else {
+    ("created", client.post(&terms_endpoint, &request_body))
+  };
+
+  let (status, response_body) = result?;
+  let mut term = parse_response(status, &response_body)?;
+  term["operation"] = json!(operation);
+
+  Ok(term)
+}
+
+/// WooCommerce search is a substring match and names come back HTML-escaped,
+/// so the unescaped name is compared case-insensitively afterwards
+fn find_term_by_name(client: &ApiClient, terms_endpoint: &str, name: &str) -> Result<Option<Value>, AppError> {
+  let endpoint = format!("{}?search={}", terms_endpoint, urlencoding::encode(name.trim()));
+  let terms = get_all_pages(client, &endpoint, MAX_PAGES)?;
+
+  Ok(terms.into_iter().find(|term| {
+    term.get("name")
+      .and_then(|v| v.as_str())
+      .is_some_and(|n| term_name_matches(n, name))
+  }))
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("../../schemas/shared/product_attribute_term_base_output_schema.json");
+  let mut schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  schema["properties"]["operation"] = json!({
+    "title": "Utförd åtgärd",
+    "description": "Om termen skapades, uppdaterades eller redan fanns.",
+    "type": "string",
+    "enum": ["created", "updated", "existing"]
+  });
+  Ok(schema)
+}
@@ -0,0 +1,241 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{batch_create_reporting_failures, batch_item_error, parse_response, request_body_without_empty_values, resolve_shipping_class, resolve_tag_names};
+use serde_json::{json, Value};
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let attributes: Vec<Value> = input_data.get("attributes")
+    .and_then(|v| v.as_array())
+    .cloned()
+    .unwrap_or_default()
+    .into_iter()
+    .filter(|a| a.get("options").and_then(|o| o.as_array()).is_some_and(|o| !o.is_empty()))
+    .collect();
+
+  if attributes.is_empty() {
+    return Err(AppError {
+      code: ErrorCode::Misconfigured,
+      message: "At least one attribute with options is required".to_string(),
+    });
+  }
+
+  let variations = build_variations(&input_data, &attributes)?;
+
+  let mut product_body = request_body_without_empty_values(&input_data, &[
+    "attributes", "regular_price", "stock_quantity", "sku_separator", "rules",
+  ])?;
+  resolve_tag_names(&client, &mut product_body)?;
+  resolve_shipping_class(&client, &mut product_body)?;
+  product_body["type"] = json!("variable");
+  if product_body.get("status").is_none() {
+    product_body["status"] = json!("draft");
+  }
+  product_body["attributes"] = Value::Array(attributes.iter()
+    .enumerate()
+    .map(|(position, attribute)| {
+      let mut product_attribute = json!({
+        "name": attribute["name"],
+        "options": attribute["options"],
+        "position": position,
+        "visible": true,
+        "variation": true
+      });
+      if let Some(id) = attribute.get("id").and_then(|v| v.as_i64()) {
+        product_attribute["id"] = json!(id);
+      }
+      product_attribute
+    })
+    .collect());
+
+  let (status, body) = client.post("/products", &product_body)?;
+  let product = parse_response(status, &body)?;
+
+  let product_id = product.get("id").and_then(|v| v.as_i64()).ok_or_else(|| AppError {
+    code: ErrorCode::MalformedResponse,
+    message: "WooCommerce returned a product without id".to_string(),
+  })?;
+
+  let mut created = Vec::new();
+  let mut errors = Vec::new();
+
+  // The product exists at this point, so failed requests are reported per variation instead of
+  // failing the action and hiding the product id
+  let results = batch_create_reporting_failures(&client, &format!("/products/{}/variations/batch", product_id), &variations);
+  for (variation, sent) in results.into_iter().zip(&variations) {
+    match batch_item_error(&variation) {
+      Some(mut error) => {
+        error["sku"] = sent.get("sku").cloned().unwrap_or(Value::Null);
+        errors.push(error);
+      }
+      None => created.push(variation),
+    }
+  }
+
+  Ok(json!({
+    "product_id": product_id,
+    "product": product,
+    "variations": created,
+    "errors": errors
+  }))
+}
+
+/// Expand the attribute options into every combination and apply the rules to each
+fn build_variations(input_data: &Value, attributes: &[Value]) -> Result<Vec<Value>, AppError> {
+  let base_sku = input_data.get("sku").and_then(|v| v.as_str()).unwrap_or_default();
+  let separator = input_data.get("sku_separator").and_then(|v| v.as_str()).unwrap_or("-");
+  let base_price = input_data.get("regular_price")
+    .and_then(|v| v.as_str())
+    .filter(|s| !s.is_empty());
+  let base_stock = input_data.get("stock_quantity").and_then(|v| v.as_i64());
+  let rules = input_data.get("rules").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+
+  let mut variations = Vec::new();
+
+  for combination in combinations(attributes) {
+    let mut price_override: Option<String> = None;
+    let mut adjustment = 0.0;
+    let mut stock = base_stock;
+    let mut sku_parts = Vec::new();
+    let mut variation_attributes = Vec::new();
+
+    for (attribute, option) in attributes.iter().zip(&combination) {
+      let name = attribute.get("name").and_then(|v| v.as_str()).unwrap_or_default();
+      let mut sku_part = default_sku_part(option);
+
+      for rule in rules.iter().filter(|rule| rule_matches(rule, name, option)) {
+        if let Some(price) = rule.get("regular_price").and_then(|v| v.as_str()).filter(|s| !s.is_empty()) {
+          price_override = Some(price.to_string());
+        }
+        adjustment += rule.get("price_adjustment").and_then(|v| v.as_f64()).unwrap_or(0.0);
+        if let Some(quantity) = rule.get("stock_quantity").and_then(|v| v.as_i64()) {
+          stock = Some(quantity);
+        }
+        if let Some(part) = rule.get("sku_part").and_then(|v| v.as_str()).filter(|s| !s.is_empty()) {
+          sku_part = part.to_string();
+        }
+      }
+
+      sku_parts.push(sku_part);
+
+      let mut variation_attribute = json!({ "option": option });
+      match attribute.get("id").and_then(|v| v.as_i64()) {
+        Some(id) => variation_attribute["id"] = json!(id),
+        None => variation_attribute["name"] = json!(name),
+      }
+      variation_attributes.push(variation_attribute);
+    }
+
+    let mut variation = json!({ "attributes": variation_attributes });
+
+    if !base_sku.is_empty() {
+      variation["sku"] = json!(format!("{}{}{}", base_sku, separator, sku_parts.join(separator)));
+    }
+
+    let price = match (price_override, base_price) {
+      (Some(price), _) => Some(price),
+      (None, Some(base)) => {
+        let base: f64 = base.parse().map_err(|_| AppError {
+          code: ErrorCode::Misconfigured,
+          message: format!("regular_price '{}' is not a valid number", base),
+        })?;
+        Some(format_price(base + adjustment))
+      }
+      (None, None) => None,
+    };
+    if let Some(price) = price {
+      variation["regular_price"] = json!(price);
+    }
+
+    if let Some(quantity) = stock {
+      variation["manage_stock"] = json!(true);
+      variation["stock_quantity"] = json!(quantity);
+    }
+
+    variations.push(variation);
+  }
+
+  Ok(variations)
+}
+
+/// Cartesian product of all attribute options, keeping the attribute order
+fn combinations(attributes: &[Value]) -> Vec<Vec<String>> {
+  attributes.iter().fold(vec![Vec::new()], |acc, attribute| {
+    let options: Vec<String> = attribute.get("options")
+      .and_then(|v| v.as_array())
+      .map(|options| options.iter().filter_map(|o| o.as_str()).map(|o| o.to_string()).collect())
+      .unwrap_or_default();
+
+    acc.into_iter()
+      .flat_map(|prefix| {
+        options.iter().map(move |option| {
+          let mut combination = prefix.clone();
+          combination.push(option.clone());
+          combination
+        })
+      })
+      .collect()
+  })
+}
+
+fn rule_matches(rule: &Value, attribute: &str, option: &str) -> bool {
+  let rule_attribute = rule.get("attribute").and_then(|v| v.as_str()).unwrap_or_default();
+  let rule_option = rule.get("option").and_then(|v| v.as_str()).unwrap_or_default();
+  normalize(rule_attribute) == normalize(attribute) && normalize(rule_option) == normalize(option)
+}
+
+fn normalize(value: &str) -> String {
+  value.trim().to_lowercase()
+}
+
+/// `Extra Large` becomes `EXTRA-LARGE`
+fn default_sku_part(option: &str) -> String {
+  option.split_whitespace()
+    .collect::<Vec<_>>()
+    .join("-")
+    .to_uppercase()
+}
+
+fn format_price(price: f64) -> String {
+  if price.fract() == 0.0 {
+    format!("{:.0}", price)
+  } else {
+    format!("{:.2}", price)
+  }
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
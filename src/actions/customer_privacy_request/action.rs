@@ -0,0 +1,261 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{batch_item_error, batch_update, filter_customer_data, get_all_pages_with_more, handle_not_found, parse_response};
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+
+const MAX_ORDER_PAGES: u32 = 50;
+
+/// Address fields that are blanked when anonymizing. Country is kept for VAT reporting.
+const ADDRESS_FIELDS: [&str; 10] = [
+  "first_name", "last_name", "company", "address_1", "address_2",
+  "city", "state", "postcode", "email", "phone",
+];
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let on_not_found = input_data.get("on_not_found")
+    .and_then(|v| v.as_str())
+    .unwrap_or("fail");
+
+  let mode = input_data.get("mode")
+    .and_then(|v| v.as_str())
+    .unwrap_or("export");
+
+  let customer_id = input_data.get("customerId")
+    .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse::<i64>().ok())))
+    .ok_or_else(|| AppError {
+      code: ErrorCode::Misconfigured,
+      message: "customerId parameter is required".to_string(),
+    })?;
+
+  let endpoint = format!("/customers/{}", customer_id);
+  let (status, body) = client.get(&endpoint)?;
+
+  if status == 404 {
+    return handle_not_found(on_not_found, "Customer", &endpoint);
+  }
+
+  let customer = filter_customer_data(parse_response(status, &body)?);
+  let (orders, has_more) = customer_orders(&client, customer_id, &customer)?;
+
+  let allow_incomplete = input_data.get("allow_incomplete")
+    .and_then(|v| v.as_bool())
+    .unwrap_or(false);
+
+  match mode {
+    "export" => export_customer(&client, customer_id, customer, orders, has_more),
+    // Orders beyond the page limit would keep the personal data while the request looks fulfilled
+    "anonymize" if has_more && !allow_incomplete => Err(AppError {
+      code: ErrorCode::Misconfigured,
+      message: format!(
+        "Customer {} has more orders than could be read, set allow_incomplete to anonymize only the orders found",
+        customer_id
+      ),
+    }),
+    "anonymize" => anonymize_customer(&client, customer_id, &orders, has_more),
+    _ => Err(AppError {
+      code: ErrorCode::Misconfigured,
+      message: format!("Unknown mode '{}', expected 'export' or 'anonymize'", mode),
+    }),
+  }
+}
+
+/// Orders linked to the customer account plus guest orders placed with one of the customer's
+/// email addresses, which WooCommerce doesn't link to the account. The flag tells whether any
+/// lookup stopped at the page limit.
+fn customer_orders(client: &ApiClient, customer_id: i64, customer: &Value) -> Result<(Vec<Value>, bool), AppError> {
+  let (mut orders, mut has_more) = get_all_pages_with_more(client, &format!("/orders?customer={}", customer_id), MAX_ORDER_PAGES)?;
+
+  for email in customer_emails(customer) {
+    // Search also matches names and addresses, so only exact billing email matches are kept
+    let (found, more_found) = get_all_pages_with_more(client, &format!("/orders?search={}", urlencoding::encode(&email)), MAX_ORDER_PAGES)?;
+    has_more |= more_found;
+
+    for order in found {
+      let is_guest_order = order.get("customer_id").and_then(|v| v.as_i64()).unwrap_or(0) == 0;
+      let same_email = order.pointer("/billing/email")
+        .and_then(|v| v.as_str())
+        .is_some_and(|billing_email| billing_email.trim().to_lowercase() == email);
+      let known = orders.iter().any(|existing| existing.get("id") == order.get("id"));
+
+      if is_guest_order && same_email && !known {
+        orders.push(order);
+      }
+    }
+  }
+
+  Ok((orders, has_more))
+}
+
+fn customer_emails(customer: &Value) -> BTreeSet<String> {
+  [customer.get("email"), customer.pointer("/billing/email")].into_iter()
+    .flatten()
+    .filter_map(|v| v.as_str())
+    .map(|email| email.trim().to_lowercase())
+    .filter(|email| !email.is_empty())
+    .collect()
+}
+
+/// Lowercased email addresses, names and phone number an order note can mention the customer by
+fn customer_identifiers(customer: &Value) -> BTreeSet<String> {
+  let text = |pointer: &str| customer.pointer(pointer).and_then(|v| v.as_str()).unwrap_or_default().trim();
+
+  let mut identifiers = customer_emails(customer);
+  for (first_name, last_name) in [("/first_name", "/last_name"), ("/billing/first_name", "/billing/last_name")] {
+    identifiers.insert(format!("{} {}", text(first_name), text(last_name)).trim().to_lowercase());
+  }
+  identifiers.insert(text("/billing/phone").to_lowercase());
+  identifiers.remove("");
+  identifiers
+}
+
+fn export_customer(
+  client: &ApiClient,
+  customer_id: i64,
+  customer: Value,
+  orders: Vec<Value>,
+  mut has_more: bool,
+) -> Result<Value, AppError> {
+  // Most notes are status changes, only the ones that mention the customer hold personal data
+  let identifiers = customer_identifiers(&customer);
+  let mut order_notes = Vec::new();
+  for order_id in orders.iter().filter_map(|o| o.get("id").and_then(|v| v.as_i64())) {
+    let (status, body) = client.get(&format!("/orders/{}/notes", order_id))?;
+    if let Value::Array(notes) = parse_response(status, &body)? {
+      order_notes.extend(notes.into_iter()
+        .filter(|note| {
+          let text = note.get("note").and_then(|v| v.as_str()).unwrap_or_default().to_lowercase();
+          identifiers.iter().any(|identifier| text.contains(identifier.as_str()))
+        })
+        .map(|mut note| {
+          note["order_id"] = json!(order_id);
+          note
+        }));
+    }
+  }
+
+  let reviews = match customer.get("email").and_then(|v| v.as_str()).filter(|s| !s.is_empty()) {
+    Some(email) => {
+      let (reviews, more_reviews) = get_all_pages_with_more(
+        client,
+        &format!("/products/reviews?reviewer_email={}&status=all", urlencoding::encode(email)),
+        MAX_ORDER_PAGES,
+      )?;
+      has_more |= more_reviews;
+      reviews
+    }
+    None => Vec::new(),
+  };
+
+  let (status, body) = client.get(&format!("/customers/{}/downloads", customer_id))?;
+  let downloads = parse_response(status, &body)?;
+
+  Ok(json!({
+    "mode": "export",
+    "customer_id": customer_id,
+    "customer": customer,
+    "orders": orders,
+    "order_notes": order_notes,
+    "reviews": reviews,
+    "downloads": downloads,
+    "has_more": has_more
+  }))
+}
+
+/// Blank personal data on the customer and on all of their orders
+/// Order totals, line items and the billing country are left untouched
+fn anonymize_customer(client: &ApiClient, customer_id: i64, orders: &[Value], has_more: bool) -> Result<Value, AppError> {
+  // WooCommerce requires customers to keep a unique, valid email address
+  let mut billing = blank_address();
+  billing["email"] = json!(format!("deleted-{}@site.invalid", customer_id));
+
+  let customer_body = json!({
+    "email": format!("deleted-{}@site.invalid", customer_id),
+    "first_name": "",
+    "last_name": "",
+    "billing": billing,
+    "shipping": blank_address()
+  });
+
+  let (status, body) = client.put(&format!("/customers/{}", customer_id), &customer_body)?;
+  let customer = filter_customer_data(parse_response(status, &body)?);
+
+  let order_ids: Vec<i64> = orders.iter()
+    .filter_map(|o| o.get("id").and_then(|v| v.as_i64()))
+    .collect();
+
+  let updates: Vec<Value> = order_ids.iter()
+    .map(|id| json!({
+      "id": id,
+      "billing": blank_address(),
+      "shipping": blank_address(),
+      "customer_note": ""
+    }))
+    .collect();
+
+  let mut anonymized_order_ids = Vec::new();
+  let mut errors = Vec::new();
+
+  for (result, order_id) in batch_update(client, "/orders/batch", &updates)?.iter().zip(&order_ids) {
+    match batch_item_error(result) {
+      Some(mut error) => {
+        error["order_id"] = json!(order_id);
+        errors.push(error);
+      }
+      None => anonymized_order_ids.push(*order_id),
+    }
+  }
+
+  Ok(json!({
+    "mode": "anonymize",
+    "customer_id": customer_id,
+    "customer": customer,
+    "anonymized_order_ids": anonymized_order_ids,
+    "errors": errors,
+    "has_more": has_more
+  }))
+}
+
+fn blank_address() -> Value {
+  let fields: serde_json::Map<String, Value> = ADDRESS_FIELDS.iter()
+    .map(|field| (field.to_string(), json!("")))
+    .collect();
+  Value::Object(fields)
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
@@ -0,0 +1,87 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{filter_customer_data, handle_not_found};
+use serde_json::Value;
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let on_not_found = input_data.get("on_not_found")
+    .and_then(|v| v.as_str())
+    .unwrap_or("fail");
+
+  let customer_id = input_data.get("customerId")
+    .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse::<i64>().ok())))
+    .ok_or_else(|| AppError {
+      code: ErrorCode::Misconfigured,
+      message: "customerId parameter is required".to_string(),
+    })?;
+
+  let reassign = input_data.get("reassign")
+    .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse::<i64>().ok())));
+
+  // Customers cannot be trashed, WooCommerce rejects the request unless force is true
+  let mut endpoint = format!("/customers/{}?force=true", customer_id);
+  if let Some(user_id) = reassign {
+    endpoint = format!("{}&reassign={}", endpoint, user_id);
+  }
+
+  let (status, body) = client.delete_with_status(&endpoint)?;
+
+  // WooCommerce answers 400 with woocommerce_rest_invalid_id when the customer doesn't exist
+  let invalid_id = status == 400 && serde_json::from_str::<Value>(&body)
+    .is_ok_and(|error| error.get("code").and_then(|v| v.as_str()) == Some("woocommerce_rest_invalid_id"));
+
+  if status == 404 || invalid_id {
+    return handle_not_found(on_not_found, "Customer", &endpoint);
+  }
+
+  if status >= 400 {
+    return Err(AppError {
+      code: ErrorCode::Other,
+      message: format!("WooCommerce returnerade felkod {}: {}", status, body),
+    });
+  }
+
+  let deleted_customer: Value = serde_json::from_str(&body).map_err(|e| AppError {
+    code: ErrorCode::MalformedResponse,
+    message: format!("Kunde inte tolka raderings-svaret som JSON: {}", e),
+  })?;
+
+  Ok(filter_customer_data(deleted_customer))
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("../retrieve_customer_by_id/base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
@@ -0,0 +1,207 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{batch_create_reporting_failures, batch_item_error, get_all_pages, parse_response, required_id_parameter};
+use serde_json::{json, Map, Value};
+
+const MAX_PAGES: u32 = 20;
+
+/// Fields WooCommerce computes or that must stay unique, they are left out of the copy
+const READ_ONLY_FIELDS: [&str; 26] = [
+  "id", "slug", "permalink", "parent_id", "date_created", "date_created_gmt", "date_modified",
+  "date_modified_gmt", "date_on_sale_from_gmt", "date_on_sale_to_gmt", "price", "price_html",
+  "on_sale", "purchasable", "total_sales", "backorders_allowed", "backordered", "shipping_required",
+  "shipping_taxable", "shipping_class_id", "average_rating", "rating_count", "related_ids",
+  "variations", "global_unique_id", "_links",
+];
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let source_id = required_id_parameter(&input_data, "productId")?;
+  let sku_scheme = SkuScheme::from_input(&input_data);
+  let copy_stock = input_data.get("copy_stock")
+    .and_then(|v| v.as_bool())
+    .unwrap_or(false);
+
+  let include_variations = input_data.get("include_variations")
+    .and_then(|v| v.as_bool())
+    .unwrap_or(true);
+
+  let (status, body) = client.get(&format!("/products/{}", source_id))?;
+  let source = parse_response(status, &body)?;
+
+  if source.get("type").and_then(|v| v.as_str()) == Some("variation") {
+    return Err(AppError {
+      code: ErrorCode::Misconfigured,
+      message: "productId refers to a variation, duplicate its parent product instead".to_string(),
+    });
+  }
+
+  let mut product_body = copy_fields(&source, &sku_scheme, copy_stock);
+
+  let name = input_data.get("name")
+    .and_then(|v| v.as_str())
+    .filter(|s| !s.trim().is_empty())
+    .map(|s| s.to_string())
+    .unwrap_or_else(|| format!("{} (kopia)", source.get("name").and_then(|v| v.as_str()).unwrap_or_default()));
+  product_body.insert("name".to_string(), json!(name));
+
+  let status = input_data.get("status")
+    .and_then(|v| v.as_str())
+    .filter(|s| !s.is_empty())
+    .unwrap_or("draft");
+  product_body.insert("status".to_string(), json!(status));
+
+  let (status, body) = client.post("/products", &Value::Object(product_body))?;
+  let product = parse_response(status, &body)?;
+
+  let product_id = product.get("id").and_then(|v| v.as_i64()).ok_or_else(|| AppError {
+    code: ErrorCode::MalformedResponse,
+    message: "WooCommerce returned a product without id".to_string(),
+  })?;
+
+  let mut created = Vec::new();
+  let mut errors = Vec::new();
+
+  // The copy exists at this point, so failures are reported in the output instead of failing the
+  // action and hiding the product id
+  if include_variations && source.get("type").and_then(|v| v.as_str()) == Some("variable") {
+    let variations: Vec<Value> = match get_all_pages(&client, &format!("/products/{}/variations", source_id), MAX_PAGES) {
+      Ok(variations) => variations.iter()
+        .map(|variation| Value::Object(copy_fields(variation, &sku_scheme, copy_stock)))
+        .collect(),
+      Err(e) => {
+        errors.push(json!({ "code": "variations_not_read", "message": e.message }));
+        Vec::new()
+      }
+    };
+
+    let results = batch_create_reporting_failures(&client, &format!("/products/{}/variations/batch", product_id), &variations);
+    for (variation, sent) in results.into_iter().zip(&variations) {
+      match batch_item_error(&variation) {
+        Some(mut error) => {
+          error["sku"] = sent.get("sku").cloned().unwrap_or(Value::Null);
+          errors.push(error);
+        }
+        None => created.push(variation),
+      }
+    }
+  }
+
+  Ok(json!({
+    "source_id": source_id,
+    "product_id": product_id,
+    "product": product,
+    "variations": created,
+    "errors": errors
+  }))
+}
+
+/// Copy everything writable from a product or variation. Images keep pointing at the same
+/// media library attachments, and meta data and downloads lose their ids so new ones are created.
+/// Stock quantities are only copied on request, a copy made for a new season starts without stock.
+fn copy_fields(source: &Value, sku_scheme: &SkuScheme, copy_stock: bool) -> Map<String, Value> {
+  let mut copy = source.as_object().cloned().unwrap_or_default();
+  copy.retain(|key, value| !value.is_null() && !READ_ONLY_FIELDS.contains(&key.as_str()));
+
+  // Variations report "parent" when they use the parent's stock, which is written as false
+  if let Some(manage_stock) = copy.get_mut("manage_stock") {
+    *manage_stock = json!(manage_stock.as_bool() == Some(true));
+  }
+  if !copy_stock {
+    copy.remove("stock_quantity");
+  }
+
+  if let Some(images) = copy.get_mut("images").and_then(|v| v.as_array_mut()) {
+    for image in images.iter_mut() {
+      *image = json!({ "id": image.get("id") });
+    }
+  }
+  if let Some(image) = copy.get_mut("image").filter(|v| v.is_object()) {
+    *image = json!({ "id": image.get("id") });
+  }
+
+  for field in ["meta_data", "downloads"] {
+    if let Some(items) = copy.get_mut(field).and_then(|v| v.as_array_mut()) {
+      for item in items.iter_mut().filter_map(|item| item.as_object_mut()) {
+        item.remove("id");
+      }
+    }
+  }
+
+  let sku = copy.get("sku").and_then(|v| v.as_str()).unwrap_or_default();
+  copy.insert("sku".to_string(), json!(sku_scheme.apply(sku)));
+
+  copy
+}
+
+/// SKUs have to be unique, so the copy gets a rewritten SKU or none at all
+struct SkuScheme {
+  find: Option<String>,
+  replace: String,
+  suffix: Option<String>,
+}
+
+impl SkuScheme {
+  fn from_input(input_data: &Value) -> Self {
+    let text = |name: &str| {
+      input_data.get(name)
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+    };
+
+    Self {
+      find: text("sku_find"),
+      replace: text("sku_replace").unwrap_or_default(),
+      suffix: text("sku_suffix"),
+    }
+  }
+
+  fn apply(&self, sku: &str) -> String {
+    if sku.is_empty() {
+      return String::new();
+    }
+
+    match (&self.find, &self.suffix) {
+      (Some(find), _) if sku.contains(find.as_str()) => sku.replace(find.as_str(), &self.replace),
+      (_, Some(suffix)) => format!("{}{}", sku, suffix),
+      _ => String::new(),
+    }
+  }
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
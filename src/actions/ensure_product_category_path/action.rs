@@ -0,0 +1,107 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{get_all_pages, parse_response, term_name_matches};
+use serde_json::{json, Value};
+
+const MAX_PAGES: u32 = 20;
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let path = input_data.get("path")
+    .and_then(|v| v.as_str())
+    .unwrap_or_default();
+
+  let separator = input_data.get("separator")
+    .and_then(|v| v.as_str())
+    .filter(|s| !s.is_empty())
+    .unwrap_or(">");
+
+  let names: Vec<&str> = path.split(separator)
+    .map(|name| name.trim())
+    .filter(|name| !name.is_empty())
+    .collect();
+
+  if names.is_empty() {
+    return Err(AppError {
+      code: ErrorCode::Misconfigured,
+      message: "path must contain at least one category name".to_string(),
+    });
+  }
+
+  let mut parent = 0;
+  let mut categories = Vec::new();
+
+  for name in names {
+    let (category, created) = match find_child_by_name(&client, parent, name)? {
+      Some(category) => (category, false),
+      None => {
+        let (status, body) = client.post("/products/categories", &json!({ "name": name, "parent": parent }))?;
+        (parse_response(status, &body)?, true)
+      }
+    };
+
+    parent = category.get("id").and_then(|v| v.as_i64()).ok_or_else(|| AppError {
+      code: ErrorCode::MalformedResponse,
+      message: format!("WooCommerce returned category '{}' without id", name),
+    })?;
+
+    categories.push(json!({
+      "id": parent,
+      "name": category.get("name"),
+      "slug": category.get("slug"),
+      "parent": category.get("parent"),
+      "created": created
+    }));
+  }
+
+  Ok(json!({ "id": parent, "categories": categories }))
+}
+
+/// WooCommerce search is a substring match and names come back HTML-escaped,
+/// so the direct children of `parent` are compared on their unescaped name
+fn find_child_by_name(client: &ApiClient, parent: i64, name: &str) -> Result<Option<Value>, AppError> {
+  let endpoint = format!("/products/categories?parent={}&search={}", parent, urlencoding::encode(name));
+  let children = get_all_pages(client, &endpoint, MAX_PAGES)?;
+
+  Ok(children.into_iter().find(|category| {
+    category.get("name")
+      .and_then(|v| v.as_str())
+      .is_some_and(|n| term_name_matches(n, name))
+  }))
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
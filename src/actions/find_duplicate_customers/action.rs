@@ -0,0 +1,310 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::get_all_pages_with_more;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+const MAX_PAGES: u32 = 100;
+
+/// Matching rules and the confidence each one gives on its own
+const RULES: [(&str, f64); 4] = [
+  ("email", 0.95),
+  ("email_plus_addressing", 0.85),
+  ("phone", 0.75),
+  ("name_address", 0.7),
+];
+
+/// Confidence, the two member indexes and the rules they share
+type Link<'a> = (f64, usize, usize, BTreeSet<&'a str>);
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let include_guest_orders = input_data.get("include_guest_orders")
+    .and_then(|v| v.as_bool())
+    .unwrap_or(false);
+
+  let min_confidence = input_data.get("min_confidence")
+    .and_then(|v| v.as_f64())
+    .unwrap_or(0.5);
+
+  let (customers, mut has_more) = get_all_pages_with_more(&client, "/customers?role=all&orderby=id", MAX_PAGES)?;
+  let mut members: Vec<Value> = customers.iter().map(customer_member).collect();
+  let scanned_customers = members.len();
+
+  if include_guest_orders {
+    let (guest_orders, more_guest_orders) = get_all_pages_with_more(&client, "/orders?customer=0", MAX_PAGES)?;
+    has_more |= more_guest_orders;
+    members.extend(guest_members(&guest_orders));
+  }
+  let scanned_guests = members.len() - scanned_customers;
+
+  let clusters = find_clusters(&members, min_confidence);
+
+  Ok(json!({
+    "clusters": clusters,
+    "scanned_customers": scanned_customers,
+    "scanned_guests": scanned_guests,
+    "has_more": has_more
+  }))
+}
+
+fn customer_member(customer: &Value) -> Value {
+  let billing = customer.get("billing").cloned().unwrap_or(json!({}));
+  let email = text(customer, "email").or_else(|| text(&billing, "email"));
+  json!({
+    "type": "customer",
+    "customer_id": customer.get("id"),
+    "email": email,
+    "first_name": text(customer, "first_name").or_else(|| text(&billing, "first_name")),
+    "last_name": text(customer, "last_name").or_else(|| text(&billing, "last_name")),
+    "phone": text(&billing, "phone"),
+    "address_1": text(&billing, "address_1"),
+    "postcode": text(&billing, "postcode")
+  })
+}
+
+/// Guest orders with the same email address are collapsed into one member
+fn guest_members(orders: &[Value]) -> Vec<Value> {
+  let mut guests: BTreeMap<String, Value> = BTreeMap::new();
+  let mut anonymous = Vec::new();
+
+  for order in orders {
+    let billing = order.get("billing").cloned().unwrap_or(json!({}));
+    let order_id = order.get("id").cloned().unwrap_or(Value::Null);
+    let email = text(&billing, "email");
+
+    let key = email.as_deref().map(|e| e.trim().to_lowercase());
+    if let Some(existing) = key.as_ref().and_then(|k| guests.get_mut(k)) {
+      if let Some(ids) = existing["order_ids"].as_array_mut() {
+        ids.push(order_id);
+      }
+      continue;
+    }
+
+    let member = json!({
+      "type": "guest",
+      "order_ids": [order_id],
+      "email": email,
+      "first_name": text(&billing, "first_name"),
+      "last_name": text(&billing, "last_name"),
+      "phone": text(&billing, "phone"),
+      "address_1": text(&billing, "address_1"),
+      "postcode": text(&billing, "postcode")
+    });
+
+    match key {
+      Some(k) => { guests.insert(k, member); }
+      None => anonymous.push(member),
+    }
+  }
+
+  guests.into_values().chain(anonymous).collect()
+}
+
+/// Link members that share a normalised key for any rule and score every linked pair on the
+/// rules it shares. Pairs are joined strongest first and pairs below `min_confidence` are left out,
+/// so a cluster is only as confident as its weakest link.
+fn find_clusters(members: &[Value], min_confidence: f64) -> Vec<Value> {
+  let mut pair_rules: BTreeMap<(usize, usize), BTreeSet<&str>> = BTreeMap::new();
+
+  for (rule, _) in RULES {
+    let mut by_key: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (index, member) in members.iter().enumerate() {
+      if let Some(key) = rule_key(rule, member) {
+        by_key.entry(key).or_default().push(index);
+      }
+    }
+
+    for indexes in by_key.into_values().filter(|indexes| indexes.len() > 1) {
+      for (position, a) in indexes.iter().enumerate() {
+        for b in &indexes[position + 1..] {
+          // Identical addresses are already covered by the plain email rule
+          if rule == "email_plus_addressing" && rule_key("email", &members[*a]) == rule_key("email", &members[*b]) {
+            continue;
+          }
+          pair_rules.entry((*a, *b)).or_default().insert(rule);
+        }
+      }
+    }
+  }
+
+  let mut links: Vec<Link> = pair_rules.into_iter()
+    .map(|((a, b), rules)| (pair_confidence(&rules), a, b, rules))
+    .filter(|(confidence, ..)| *confidence >= min_confidence)
+    .collect();
+  links.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+  // Keep the strongest link that joins two groups, which leaves each cluster's weakest needed link
+  let mut parents: Vec<usize> = (0..members.len()).collect();
+  let mut joined = Vec::new();
+  for link in links {
+    if find(&mut parents, link.1) != find(&mut parents, link.2) {
+      union(&mut parents, link.1, link.2);
+      joined.push(link);
+    }
+  }
+
+  let mut by_root: BTreeMap<usize, Vec<Link>> = BTreeMap::new();
+  for link in joined {
+    let root = find(&mut parents, link.1);
+    by_root.entry(root).or_default().push(link);
+  }
+
+  let mut clusters: Vec<Value> = by_root.into_values()
+    .map(|cluster_links| {
+      let indexes: BTreeSet<usize> = cluster_links.iter().flat_map(|(_, a, b, _)| [*a, *b]).collect();
+      let position = |index: &usize| indexes.iter().position(|i| i == index);
+      let confidence = cluster_links.iter().map(|(confidence, ..)| *confidence).fold(1.0, f64::min);
+      let reasons: BTreeSet<&str> = cluster_links.iter().flat_map(|(_, _, _, rules)| rules.iter().copied()).collect();
+
+      let pairs: Vec<Value> = cluster_links.iter()
+        .map(|(confidence, a, b, rules)| json!({
+          "members": [position(a), position(b)],
+          "confidence": confidence,
+          "reasons": rules
+        }))
+        .collect();
+
+      json!({
+        "confidence": confidence,
+        "reasons": reasons,
+        "links": pairs,
+        "members": indexes.iter().map(|index| public_member(&members[*index])).collect::<Vec<_>>()
+      })
+    })
+    .collect();
+
+  clusters.sort_by(|a, b| {
+    let a = a["confidence"].as_f64().unwrap_or(0.0);
+    let b = b["confidence"].as_f64().unwrap_or(0.0);
+    b.total_cmp(&a)
+  });
+
+  clusters
+}
+
+/// Independent signals reinforce each other: 1 - product of (1 - confidence)
+fn pair_confidence(rules: &BTreeSet<&str>) -> f64 {
+  let miss: f64 = RULES.iter()
+    .filter(|(rule, _)| rules.contains(rule))
+    .map(|(_, confidence)| 1.0 - confidence)
+    .product();
+  ((1.0 - miss) * 100.0).round() / 100.0
+}
+
+fn rule_key(rule: &str, member: &Value) -> Option<String> {
+  match rule {
+    "email" => text(member, "email").map(|e| e.trim().to_lowercase()),
+    "email_plus_addressing" => text(member, "email").and_then(|e| strip_plus_addressing(&e)),
+    "phone" => text(member, "phone").and_then(|p| normalize_phone(&p)),
+    "name_address" => {
+      let name = format!(
+        "{} {}",
+        text(member, "first_name").unwrap_or_default(),
+        text(member, "last_name").unwrap_or_default()
+      );
+      let name = squash(&name);
+      let address = squash(&text(member, "address_1")?);
+      let postcode = squash(&text(member, "postcode")?);
+      (!name.is_empty()).then(|| format!("{}|{}|{}", name, address, postcode))
+    }
+    _ => None,
+  }
+}
+
+/// `Kalle+shop@Example.com` becomes `kalle@example.com`
+fn strip_plus_addressing(email: &str) -> Option<String> {
+  let email = email.trim().to_lowercase();
+  let (local, domain) = email.split_once('@')?;
+  let base = local.split_once('+').map_or(local, |(base, _tag)| base);
+  Some(format!("{}@{}", base, domain))
+}
+
+/// Compare phone numbers on their last nine digits so that `+46 70-123 45 67`,
+/// `0046701234567` and `070 123 45 67` all match
+fn normalize_phone(phone: &str) -> Option<String> {
+  let digits: String = phone.chars().filter(|c| c.is_ascii_digit()).collect();
+  if digits.len() < 7 {
+    return None;
+  }
+  let start = digits.len().saturating_sub(9);
+  Some(digits[start..].to_string())
+}
+
+fn squash(value: &str) -> String {
+  value
+    .to_lowercase()
+    .chars()
+    .filter(|c| c.is_alphanumeric())
+    .collect()
+}
+
+fn text(value: &Value, field: &str) -> Option<String> {
+  value.get(field)
+    .and_then(|v| v.as_str())
+    .filter(|s| !s.trim().is_empty())
+    .map(|s| s.to_string())
+}
+
+fn public_member(member: &Value) -> Value {
+  let mut member = member.clone();
+  if let Some(obj) = member.as_object_mut() {
+    obj.remove("address_1");
+    obj.remove("postcode");
+    obj.retain(|_, v| !v.is_null());
+  }
+  member
+}
+
+fn find(parents: &mut [usize], index: usize) -> usize {
+  let mut root = index;
+  while parents[root] != root {
+    root = parents[root];
+  }
+  parents[index] = root;
+  root
+}
+
+fn union(parents: &mut [usize], a: usize, b: usize) {
+  let root_a = find(parents, a);
+  let root_b = find(parents, b);
+  if root_a != root_b {
+    parents[root_b] = root_a;
+  }
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
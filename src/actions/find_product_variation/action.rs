@@ -0,0 +1,143 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{find_product_by, get_all_pages, handle_not_found, id_parameter};
+use serde_json::Value;
+
+const MAX_PAGES: u32 = 20;
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let on_not_found = input_data.get("on_not_found")
+    .and_then(|v| v.as_str())
+    .unwrap_or("fail");
+
+  let wanted: Vec<(String, String)> = input_data.get("attributes")
+    .and_then(|v| v.as_object())
+    .map(|attributes| {
+      attributes.iter()
+        .filter_map(|(key, value)| value.as_str().map(|v| (normalize(key), normalize(v))))
+        .collect()
+    })
+    .unwrap_or_default();
+
+  if wanted.is_empty() {
+    return Err(AppError {
+      code: ErrorCode::Misconfigured,
+      message: "attributes must contain at least one attribute value".to_string(),
+    });
+  }
+
+  let product_id = match id_parameter(&input_data, "productId") {
+    Some(id) => id,
+    None => {
+      let sku = input_data.get("sku")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| AppError {
+          code: ErrorCode::Misconfigured,
+          message: "productId or sku is required".to_string(),
+        })?;
+
+      match find_product_by(&client, "sku", sku)?.and_then(|p| p.get("id").and_then(|v| v.as_i64())) {
+        Some(id) => id,
+        None => return handle_not_found(on_not_found, "Product", &format!("/products?sku={}", sku)),
+      }
+    }
+  };
+
+  let endpoint = format!("/products/{}/variations", product_id);
+  let variations = get_all_pages(&client, &endpoint, MAX_PAGES)?;
+
+  // Variations with fewer "any" attributes win, ties go to the first one in menu order
+  let best = variations.into_iter()
+    .rev()
+    .filter_map(|variation| match_score(&variation, &wanted).map(|score| (score, variation)))
+    .max_by_key(|(score, _)| *score)
+    .map(|(_, variation)| variation);
+
+  match best {
+    Some(variation) => Ok(variation),
+    None => handle_not_found(on_not_found, "Variation", &endpoint),
+  }
+}
+
+/// Number of attributes the variation pins to a specific value, or None if it does not match
+/// Attributes missing from a variation are "any" attributes and accept every value
+fn match_score(variation: &Value, wanted: &[(String, String)]) -> Option<usize> {
+  let attributes = variation.get("attributes").and_then(|v| v.as_array())?;
+  let mut score = 0;
+
+  for attribute in attributes {
+    let keys = attribute_keys(attribute);
+    let option = attribute.get("option").and_then(|v| v.as_str()).map(normalize).unwrap_or_default();
+
+    if option.is_empty() {
+      continue;
+    }
+
+    let value = wanted.iter().find(|(key, _)| keys.contains(key)).map(|(_, value)| value)?;
+    if *value != option && slugify(value) != slugify(&option) {
+      return None;
+    }
+    score += 1;
+  }
+
+  Some(score)
+}
+
+/// A variation attribute can be addressed by its name or by its slug, with or without the `pa_` prefix
+fn attribute_keys(attribute: &Value) -> Vec<String> {
+  let mut keys = Vec::new();
+  for field in ["name", "slug"] {
+    if let Some(value) = attribute.get(field).and_then(|v| v.as_str()).map(normalize) {
+      keys.push(slugify(&value));
+      keys.push(slugify(value.trim_start_matches("pa_")));
+      keys.push(value);
+    }
+  }
+  keys
+}
+
+fn normalize(value: &str) -> String {
+  value.trim().to_lowercase()
+}
+
+fn slugify(value: &str) -> String {
+  value.split_whitespace().collect::<Vec<_>>().join("-")
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("../../schemas/shared/variation_base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
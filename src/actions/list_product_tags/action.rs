@@ -0,0 +1,59 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{add_query_parameter, get_all_pages, items_output_schema};
+use serde_json::{json, Value};
+
+const MAX_PAGES: u32 = 20;
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let mut query_parts = Vec::new();
+  for param in ["search", "slug", "hide_empty", "orderby", "order"] {
+    add_query_parameter(&input_data, param, &mut query_parts);
+  }
+
+  let mut endpoint = "/products/tags".to_string();
+  if !query_parts.is_empty() {
+    endpoint.push('?');
+    endpoint.push_str(&query_parts.join("&"));
+  }
+
+  let tags = get_all_pages(&client, &endpoint, MAX_PAGES)?;
+
+  Ok(json!({ "items": tags }))
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let item_schema = include_str!("../../schemas/shared/product_tag_base_output_schema.json");
+  items_output_schema(item_schema, "Taggar", "Produkttaggar som matchar filtret.")
+}
@@ -0,0 +1,61 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{add_query_parameter, get_all_pages, items_output_schema, required_id_parameter};
+use serde_json::{json, Value};
+
+const MAX_PAGES: u32 = 20;
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let product_id = required_id_parameter(&input_data, "productId")?;
+
+  let mut query_parts = Vec::new();
+  for param in ["sku", "status", "stock_status", "on_sale"] {
+    add_query_parameter(&input_data, param, &mut query_parts);
+  }
+
+  let mut endpoint = format!("/products/{}/variations", product_id);
+  if !query_parts.is_empty() {
+    endpoint.push('?');
+    endpoint.push_str(&query_parts.join("&"));
+  }
+
+  let variations = get_all_pages(&client, &endpoint, MAX_PAGES)?;
+
+  Ok(json!({ "items": variations }))
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let variation_schema = include_str!("../../schemas/shared/variation_base_output_schema.json");
+  items_output_schema(variation_schema, "Varianter", "Moderproduktens varianter.")
+}
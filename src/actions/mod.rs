@@ -5,6 +5,18 @@
 pub mod utils;
 
 // Include generated action executors
+pub mod adjust_stock {
+    include!("../actions/adjust_stock/action.rs");
+}
+
+pub mod attach_product_images {
+    include!("../actions/attach_product_images/action.rs");
+}
+
+pub mod bulk_update_prices {
+    include!("../actions/bulk_update_prices/action.rs");
+}
+
 pub mod create_or_update_customer {
     include!("../actions/create_or_update_customer/action.rs");
 }
@@ -13,17 +25,161 @@ pub mod create_or_update_product {
     include!("../actions/create_or_update_product/action.rs");
 }
 
+pub mod create_or_update_product_attribute {
+    include!("../actions/create_or_update_product_attribute/action.rs");
+}
+
+pub mod create_or_update_product_attribute_term {
+    include!("../actions/create_or_update_product_attribute_term/action.rs");
+}
+
+pub mod create_or_update_product_category {
+    include!("../actions/create_or_update_product_category/action.rs");
+}
+
+pub mod create_or_update_product_review {
+    include!("../actions/create_or_update_product_review/action.rs");
+}
+
+pub mod create_or_update_product_tag {
+    include!("../actions/create_or_update_product_tag/action.rs");
+}
+
+pub mod create_or_update_product_variation {
+    include!("../actions/create_or_update_product_variation/action.rs");
+}
+
+pub mod create_or_update_shipping_class {
+    include!("../actions/create_or_update_shipping_class/action.rs");
+}
+
+pub mod create_variable_product {
+    include!("../actions/create_variable_product/action.rs");
+}
+
+pub mod customer_privacy_request {
+    include!("../actions/customer_privacy_request/action.rs");
+}
+
+pub mod delete_customer {
+    include!("../actions/delete_customer/action.rs");
+}
+
+pub mod delete_product_attribute {
+    include!("../actions/delete_product_attribute/action.rs");
+}
+
+pub mod delete_product_attribute_term {
+    include!("../actions/delete_product_attribute_term/action.rs");
+}
+
 pub mod delete_product_by_id {
     include!("../actions/delete_product_by_id/action.rs");
 }
 
+pub mod delete_product_category {
+    include!("../actions/delete_product_category/action.rs");
+}
+
+pub mod delete_product_tag {
+    include!("../actions/delete_product_tag/action.rs");
+}
+
+pub mod delete_product_variation {
+    include!("../actions/delete_product_variation/action.rs");
+}
+
+pub mod delete_shipping_class {
+    include!("../actions/delete_shipping_class/action.rs");
+}
+
+pub mod duplicate_product {
+    include!("../actions/duplicate_product/action.rs");
+}
+
+pub mod ensure_product_category_path {
+    include!("../actions/ensure_product_category_path/action.rs");
+}
+
+pub mod find_duplicate_customers {
+    include!("../actions/find_duplicate_customers/action.rs");
+}
+
+pub mod find_product_variation {
+    include!("../actions/find_product_variation/action.rs");
+}
+
+pub mod list_product_attribute_terms {
+    include!("../actions/list_product_attribute_terms/action.rs");
+}
+
+pub mod list_product_attributes {
+    include!("../actions/list_product_attributes/action.rs");
+}
+
+pub mod list_product_categories {
+    include!("../actions/list_product_categories/action.rs");
+}
+
+pub mod list_product_reviews {
+    include!("../actions/list_product_reviews/action.rs");
+}
+
+pub mod list_product_tags {
+    include!("../actions/list_product_tags/action.rs");
+}
+
+pub mod list_product_variations {
+    include!("../actions/list_product_variations/action.rs");
+}
+
+pub mod list_shipping_classes {
+    include!("../actions/list_shipping_classes/action.rs");
+}
+
+pub mod moderate_product_review {
+    include!("../actions/moderate_product_review/action.rs");
+}
+
+pub mod reconcile_inventory {
+    include!("../actions/reconcile_inventory/action.rs");
+}
+
+pub mod reply_to_product_review {
+    include!("../actions/reply_to_product_review/action.rs");
+}
+
 pub mod retrieve_customer_by_id {
     include!("../actions/retrieve_customer_by_id/action.rs");
 }
 
+pub mod retrieve_customer_summary {
+    include!("../actions/retrieve_customer_summary/action.rs");
+}
+
+pub mod retrieve_product {
+    include!("../actions/retrieve_product/action.rs");
+}
+
+pub mod retrieve_product_variation {
+    include!("../actions/retrieve_product_variation/action.rs");
+}
+
+pub mod search_customers {
+    include!("../actions/search_customers/action.rs");
+}
+
 pub mod search_products {
     include!("../actions/search_products/action.rs");
 }
 
+pub mod send_order_email {
+    include!("../actions/send_order_email/action.rs");
+}
+
+pub mod update_meta_data {
+    include!("../actions/update_meta_data/action.rs");
+}
+
 
 
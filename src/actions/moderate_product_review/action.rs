@@ -0,0 +1,69 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{parse_response, required_id_parameter};
+use serde_json::{json, Value};
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let review_id = required_id_parameter(&input_data, "reviewId")?;
+  let endpoint = format!("/products/reviews/{}", review_id);
+
+  let moderation = input_data.get("moderation")
+    .and_then(|v| v.as_str())
+    .unwrap_or_default();
+
+  let (status, body) = match moderation {
+    "approve" => client.put(&endpoint, &json!({ "status": "approved" }))?,
+    "hold" => client.put(&endpoint, &json!({ "status": "hold" }))?,
+    "spam" => client.put(&endpoint, &json!({ "status": "spam" }))?,
+    "trash" => client.delete_with_status(&endpoint)?,
+    "delete" => client.delete_with_status(&format!("{}?force=true", endpoint))?,
+    other => {
+      return Err(AppError {
+        code: ErrorCode::Misconfigured,
+        message: format!("Unknown moderation '{}', expected approve, hold, spam, trash or delete", other),
+      });
+    }
+  };
+
+  let response = parse_response(status, &body)?;
+
+  // A forced delete answers with { deleted, previous } instead of the review
+  Ok(response.get("previous").cloned().unwrap_or(response))
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("../../schemas/shared/product_review_base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
@@ -0,0 +1,176 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{batch_item_error, batch_update, get_all_pages};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+/// SKUs per lookup request, keeps the query string at a reasonable length
+const LOOKUP_SIZE: usize = 50;
+const MAX_PAGES: u32 = 5;
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  // Later entries for the same SKU win
+  let mut wanted: BTreeMap<String, i64> = BTreeMap::new();
+  let mut order: Vec<String> = Vec::new();
+  for item in input_data.get("items").and_then(|v| v.as_array()).into_iter().flatten() {
+    let sku = item.get("sku")
+      .and_then(|v| v.as_str())
+      .map(|s| s.trim())
+      .filter(|s| !s.is_empty())
+      .ok_or_else(|| AppError {
+        code: ErrorCode::Misconfigured,
+        message: "Every item needs a sku".to_string(),
+      })?;
+    let quantity = item.get("quantity").and_then(|v| v.as_i64()).ok_or_else(|| AppError {
+      code: ErrorCode::Misconfigured,
+      message: format!("quantity for SKU '{}' must be an integer", sku),
+    })?;
+
+    if wanted.insert(sku.to_string(), quantity).is_none() {
+      order.push(sku.to_string());
+    }
+  }
+
+  let current = fetch_by_sku(&client, &order)?;
+
+  let mut updated = Vec::new();
+  let mut unchanged = Vec::new();
+  let mut unknown = Vec::new();
+  let mut parent_managed = Vec::new();
+  // Simple products go through /products/batch, variations through their parent
+  let mut batches: BTreeMap<i64, Vec<(String, Value)>> = BTreeMap::new();
+
+  for sku in &order {
+    let quantity = wanted[sku];
+    let Some(item) = current.get(sku) else {
+      unknown.push(sku.clone());
+      continue;
+    };
+
+    let id = item.get("id").and_then(|v| v.as_i64()).unwrap_or_default();
+    let previous = item.get("stock_quantity").and_then(|v| v.as_i64());
+
+    // Variations that share the parent's stock are left alone, the SKU only counts part of
+    // that stock and turning on stock management would split it off from the parent
+    if item.get("manage_stock").and_then(|v| v.as_str()) == Some("parent") {
+      parent_managed.push(json!({
+        "sku": sku,
+        "id": id,
+        "parent_id": item.get("parent_id").and_then(|v| v.as_i64()).unwrap_or_default(),
+        "quantity": quantity
+      }));
+      continue;
+    }
+
+    let manage_stock = item.get("manage_stock").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    if manage_stock && previous == Some(quantity) {
+      unchanged.push(json!({ "sku": sku, "id": id, "quantity": quantity }));
+      continue;
+    }
+
+    let parent_id = if item.get("type").and_then(|v| v.as_str()) == Some("variation") {
+      item.get("parent_id").and_then(|v| v.as_i64()).unwrap_or_default()
+    } else {
+      0
+    };
+
+    batches.entry(parent_id).or_default().push((sku.clone(), json!({
+      "id": id,
+      "manage_stock": true,
+      "stock_quantity": quantity
+    })));
+
+    let mut change = json!({ "sku": sku, "id": id, "previous_quantity": previous, "quantity": quantity });
+    if parent_id > 0 {
+      change["parent_id"] = json!(parent_id);
+    }
+    updated.push(change);
+  }
+
+  let mut errors = Vec::new();
+
+  for (parent_id, changes) in batches {
+    let endpoint = if parent_id > 0 {
+      format!("/products/{}/variations/batch", parent_id)
+    } else {
+      "/products/batch".to_string()
+    };
+
+    let payload: Vec<Value> = changes.iter().map(|(_, change)| change.clone()).collect();
+    let results = batch_update(&client, &endpoint, &payload)?;
+
+    for (response, (sku, _)) in results.iter().zip(&changes) {
+      if let Some(mut error) = batch_item_error(response) {
+        error["sku"] = json!(sku);
+        errors.push(error);
+      }
+    }
+  }
+
+  let failed: Vec<&str> = errors.iter().filter_map(|e| e["sku"].as_str()).collect();
+  updated.retain(|change| !change["sku"].as_str().is_some_and(|sku| failed.contains(&sku)));
+
+  Ok(json!({
+    "updated": updated,
+    "unchanged": unchanged,
+    "unknown": unknown,
+    "parent_managed": parent_managed,
+    "errors": errors
+  }))
+}
+
+/// Look up products and variations for many SKUs at once, the sku filter accepts a comma separated list
+fn fetch_by_sku(client: &ApiClient, skus: &[String]) -> Result<BTreeMap<String, Value>, AppError> {
+  let mut found = BTreeMap::new();
+
+  for chunk in skus.chunks(LOOKUP_SIZE) {
+    let list: Vec<String> = chunk.iter().map(|sku| urlencoding::encode(sku).into_owned()).collect();
+    let endpoint = format!("/products?sku={}", list.join(","));
+
+    for item in get_all_pages(client, &endpoint, MAX_PAGES)? {
+      if let Some(sku) = item.get("sku").and_then(|v| v.as_str()).filter(|sku| chunk.iter().any(|s| s == sku)) {
+        found.insert(sku.to_string(), item);
+      }
+    }
+  }
+
+  Ok(found)
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
@@ -0,0 +1,78 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{parse_response, parse_wordpress_response, required_id_parameter};
+use serde_json::{json, Value};
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let review_id = required_id_parameter(&input_data, "reviewId")?;
+  let reply = input_data.get("reply")
+    .and_then(|v| v.as_str())
+    .filter(|s| !s.trim().is_empty())
+    .ok_or_else(|| AppError {
+      code: ErrorCode::Misconfigured,
+      message: "reply parameter is required".to_string(),
+    })?;
+
+  let (status, body) = client.get(&format!("/products/reviews/{}", review_id))?;
+  let review = parse_response(status, &body)?;
+  let product_id = review.get("product_id").and_then(|v| v.as_i64()).ok_or_else(|| AppError {
+    code: ErrorCode::MalformedResponse,
+    message: "WooCommerce returned a review without product_id".to_string(),
+  })?;
+
+  // The WooCommerce reviews endpoint has no parent field, replies are plain comments on the product
+  let (status, body) = client.for_namespace("wp/v2").post("/comments", &json!({
+    "post": product_id,
+    "parent": review_id,
+    "content": reply,
+    "status": "approved"
+  }))?;
+  let comment = parse_wordpress_response(status, &body)?;
+
+  Ok(json!({
+    "id": comment.get("id"),
+    "review_id": review_id,
+    "product_id": product_id,
+    "author_name": comment.get("author_name"),
+    "status": comment.get("status"),
+    "date": comment.get("date"),
+    "link": comment.get("link")
+  }))
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
@@ -1,7 +1,8 @@
 #[allow(unused_imports)]
 use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
 use crate::client::ApiClient;
-use serde_json::{Value, json};
+use crate::actions::utils::{filter_customer_data, handle_not_found};
+use serde_json::Value;
 
 fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
   let connection_data: serde_json::Value =
@@ -31,21 +32,7 @@ pub fn execute(context: ActionContext) -> Result<Value, AppError> {
   let (status, body) = client.get(&endpoint)?;
 
   if status == 404 {
-    return match on_not_found {
-      "continue" => Ok(json!({})),
-      "exit_level" => Err(AppError {
-        code: ErrorCode::CompleteParent,
-        message: "Stopping current level as customer was not found".to_string(),
-      }),
-      "exit_execution" => Err(AppError {
-        code: ErrorCode::CompleteWorkflow,
-        message: "Stopping entire execution as customer was not found".to_string(),
-      }),
-      _ => Err(AppError {
-        code: ErrorCode::Other,
-        message: format!("Customer not found (404) at {}", endpoint),
-      }),
-    };
+    return handle_not_found(on_not_found, "Customer", &endpoint);
   }
 
   if status != 200 {
@@ -90,15 +77,6 @@ fn build_endpoint(path_template: &str, params: &serde_json::Map<String, Value>)
   endpoint
 }
 
-fn filter_customer_data(mut customer: Value) -> Value {
-    if let Some(obj) = customer.as_object_mut() {
-        obj.remove("password");
-
-        obj.remove("_links");
-    }
-    customer
-}
-
 #[allow(dead_code)]
 pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
   let base_schema = include_str!("base_input_schema.json");
@@ -0,0 +1,176 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{get_all_pages_with_more, handle_not_found};
+use serde_json::{json, Value};
+
+const MAX_ORDER_PAGES: u32 = 50;
+const PAID_STATUSES: [&str; 2] = ["processing", "completed"];
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let on_not_found = input_data.get("on_not_found")
+    .and_then(|v| v.as_str())
+    .unwrap_or("fail");
+
+  let customer_id = input_data.get("customerId")
+    .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse::<i64>().ok())))
+    .ok_or_else(|| AppError {
+      code: ErrorCode::Misconfigured,
+      message: "customerId parameter is required".to_string(),
+    })?;
+
+  let top_products = input_data.get("top_products")
+    .and_then(|v| v.as_u64())
+    .unwrap_or(5) as usize;
+
+  let endpoint = format!("/customers/{}/downloads", customer_id);
+  let (status, body) = client.get(&endpoint)?;
+
+  if status == 404 {
+    return handle_not_found(on_not_found, "Customer", &endpoint);
+  }
+
+  if status >= 400 {
+    return Err(AppError {
+      code: ErrorCode::Other,
+      message: format!("WooCommerce returnerade felkod {}: {}", status, body),
+    });
+  }
+
+  let downloads: Vec<Value> = serde_json::from_str(&body).map_err(|e| AppError {
+    code: ErrorCode::MalformedResponse,
+    message: format!("Misslyckades att tolka JSON-svar: {}", e),
+  })?;
+
+  let (orders, has_more) = get_all_pages_with_more(&client, &format!("/orders?customer={}", customer_id), MAX_ORDER_PAGES)?;
+
+  Ok(json!({
+    "customer_id": customer_id,
+    "downloads": downloads,
+    "order_summary": summarize_orders(&orders, top_products),
+    "has_more": has_more
+  }))
+}
+
+/// Build the purchase history summary from a customer's orders
+/// Only paid orders count towards total spent and most bought products
+fn summarize_orders(orders: &[Value], top_products: usize) -> Value {
+  let mut total_spent = 0.0;
+  let mut paid_order_count = 0;
+  let mut first_order_date: Option<&str> = None;
+  let mut last_order_date: Option<&str> = None;
+  let mut products: Vec<(i64, i64, String, String, i64, f64)> = Vec::new();
+
+  for order in orders {
+    if let Some(date) = order.get("date_created").and_then(|v| v.as_str()) {
+      if first_order_date.is_none_or(|first| date < first) {
+        first_order_date = Some(date);
+      }
+      if last_order_date.is_none_or(|last| date > last) {
+        last_order_date = Some(date);
+      }
+    }
+
+    let status = order.get("status").and_then(|v| v.as_str()).unwrap_or_default();
+    if !PAID_STATUSES.contains(&status) {
+      continue;
+    }
+
+    paid_order_count += 1;
+    total_spent += amount(order.get("total"));
+
+    for item in order.get("line_items").and_then(|v| v.as_array()).into_iter().flatten() {
+      let product_id = item.get("product_id").and_then(|v| v.as_i64()).unwrap_or_default();
+      let variation_id = item.get("variation_id").and_then(|v| v.as_i64()).unwrap_or_default();
+      let quantity = item.get("quantity").and_then(|v| v.as_i64()).unwrap_or_default();
+      let line_total = amount(item.get("total"));
+
+      match products.iter_mut().find(|p| p.0 == product_id && p.1 == variation_id) {
+        Some(product) => {
+          product.4 += quantity;
+          product.5 += line_total;
+        }
+        None => products.push((
+          product_id,
+          variation_id,
+          item.get("name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+          item.get("sku").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+          quantity,
+          line_total,
+        )),
+      }
+    }
+  }
+
+  products.sort_by(|a, b| b.4.cmp(&a.4).then(a.0.cmp(&b.0)).then(a.1.cmp(&b.1)));
+
+  let top: Vec<Value> = products.into_iter()
+    .take(top_products)
+    .map(|(product_id, variation_id, name, sku, quantity, total)| json!({
+      "product_id": product_id,
+      "variation_id": variation_id,
+      "name": name,
+      "sku": sku,
+      "quantity": quantity,
+      "total": format!("{:.2}", total)
+    }))
+    .collect();
+
+  let currency = orders.first()
+    .and_then(|o| o.get("currency"))
+    .and_then(|v| v.as_str())
+    .unwrap_or_default();
+
+  json!({
+    "order_count": orders.len(),
+    "paid_order_count": paid_order_count,
+    "total_spent": format!("{:.2}", total_spent),
+    "currency": currency,
+    "first_order_date": first_order_date,
+    "last_order_date": last_order_date,
+    "top_products": top
+  })
+}
+
+/// WooCommerce returns monetary amounts as strings
+fn amount(value: Option<&Value>) -> f64 {
+  value
+    .and_then(|v| v.as_str().and_then(|s| s.parse::<f64>().ok()).or_else(|| v.as_f64()))
+    .unwrap_or(0.0)
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
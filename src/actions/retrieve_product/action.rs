@@ -0,0 +1,82 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{find_product_by, handle_not_found, parse_response};
+use serde_json::Value;
+
+/// Lookup fields in the order they are tried when several are given
+const LOOKUP_FIELDS: [&str; 3] = ["sku", "global_unique_id", "slug"];
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let on_not_found = input_data.get("on_not_found")
+    .and_then(|v| v.as_str())
+    .unwrap_or("fail");
+
+  let product_id = input_data.get("productId")
+    .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse::<i64>().ok())));
+
+  if let Some(id) = product_id {
+    // Variations are served from /products/{id} as well
+    let endpoint = format!("/products/{}", id);
+    let (status, body) = client.get(&endpoint)?;
+
+    if status == 404 {
+      return handle_not_found(on_not_found, "Product", &endpoint);
+    }
+
+    return parse_response(status, &body);
+  }
+
+  let (field, value) = LOOKUP_FIELDS.iter()
+    .find_map(|field| {
+      input_data.get(*field)
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|value| (*field, value))
+    })
+    .ok_or_else(|| AppError {
+      code: ErrorCode::Misconfigured,
+      message: "One of productId, sku, slug or global_unique_id is required".to_string(),
+    })?;
+
+  match find_product_by(&client, field, value)? {
+    Some(product) => Ok(product),
+    None => handle_not_found(on_not_found, "Product", &format!("/products?{}={}", field, value)),
+  }
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("../../schemas/shared/product_base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
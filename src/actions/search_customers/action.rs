@@ -0,0 +1,116 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{add_query_parameter, filter_customer_data, items_output_schema};
+use serde_json::{json, Value};
+
+const MAX_PER_PAGE: u64 = 100;
+const DEFAULT_MAX_PAGES: u64 = 10;
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let query_params = build_query_parameters(&input_data);
+
+  let per_page = input_data.get("per_page")
+    .and_then(|v| v.as_u64())
+    .unwrap_or(MAX_PER_PAGE)
+    .clamp(1, MAX_PER_PAGE);
+
+  let max_pages = input_data.get("max_pages")
+    .and_then(|v| v.as_u64())
+    .unwrap_or(DEFAULT_MAX_PAGES)
+    .max(1);
+
+  let mut all_customers = Vec::new();
+  let mut has_more = false;
+  let mut current_page = 1;
+
+  loop {
+    let mut endpoint = format!("/customers?page={}&per_page={}", current_page, per_page);
+    if !query_params.is_empty() {
+      endpoint.push('&');
+      endpoint.push_str(&query_params);
+    }
+
+    let (status, body) = client.get(&endpoint)?;
+
+    if status >= 400 {
+      return Err(AppError {
+        code: ErrorCode::Other,
+        message: format!("WooCommerce returnerade felkod {}: {}", status, body),
+      });
+    }
+
+    let page_customers: Vec<Value> = serde_json::from_str(&body).map_err(|e| AppError {
+      code: ErrorCode::MalformedResponse,
+      message: format!("Misslyckades att tolka JSON-svar: {}", e),
+    })?;
+
+    let fetched_count = page_customers.len();
+    all_customers.extend(page_customers.into_iter().map(filter_customer_data));
+
+    if fetched_count < per_page as usize {
+      break;
+    }
+
+    if current_page >= max_pages {
+      has_more = true;
+      break;
+    }
+
+    current_page += 1;
+  }
+
+  Ok(json!({ "items": all_customers, "has_more": has_more }))
+}
+
+fn build_query_parameters(input_data: &Value) -> String {
+  let mut query_parts = Vec::new();
+
+  let params = vec!["search", "email", "role", "orderby", "order"];
+
+  for param in params {
+    add_query_parameter(input_data, param, &mut query_parts);
+  }
+
+  query_parts.join("&")
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  Ok(serde_json::from_str(base_schema).unwrap())
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let customer_schema = include_str!("../retrieve_customer_by_id/base_output_schema.json");
+  let mut schema = items_output_schema(customer_schema, "Kunder", "En lista över kunder som matchar sökningen.")?;
+  schema["properties"]["has_more"] = json!({
+    "type": "boolean",
+    "title": "Fler träffar finns",
+    "description": "Sant om sökningen avbröts vid max antal sidor."
+  });
+  Ok(schema)
+}
@@ -1,6 +1,7 @@
 #[allow(unused_imports)]
 use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
 use crate::client::ApiClient;
+use crate::actions::utils::add_query_parameter;
 use serde_json::{json, Value};
 
 #[allow(dead_code)]
@@ -84,35 +85,6 @@ fn build_query_parameters(input_data: &Value) -> Result<String, AppError> {
   Ok(query_parts.join("&"))
 }
 
-fn add_query_parameter(input_data: &Value, param_name: &str, query_parts: &mut Vec<String>) {
-  if let Some(value) = input_data.get(param_name) {
-    match value {
-      Value::String(s) if !s.is_empty() => {
-        query_parts.push(format!("{}={}", param_name, urlencoding::encode(s)));
-      }
-      Value::Array(arr) if !arr.is_empty() => {
-        let values: Vec<String> = arr.iter()
-          .filter_map(|v| v.as_str())
-          .filter(|s| !s.is_empty())
-          .map(|s| s.to_string())
-          .collect();
-
-        if !values.is_empty() {
-          let joined = values.join(",");
-          query_parts.push(format!("{}={}", param_name, urlencoding::encode(&joined)));
-        }
-      }
-      Value::Number(n) => {
-        query_parts.push(format!("{}={}", param_name, n));
-      }
-      Value::Bool(b) => {
-        query_parts.push(format!("{}={}", param_name, b));
-      }
-      _ => {}
-    }
-  }
-}
-
 #[allow(dead_code)]
 pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
   let base_schema = include_str!("base_input_schema.json");
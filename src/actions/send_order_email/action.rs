@@ -0,0 +1,103 @@
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::request_body_without_empty_values;
+use serde_json::{Value, json};
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let order_id = input_data.get("orderId")
+    .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse::<i64>().ok())))
+    .ok_or_else(|| AppError {
+      code: ErrorCode::Misconfigured,
+      message: "orderId parameter is required".to_string(),
+    })?;
+
+  let template_id = input_data.get("template_id")
+    .and_then(|v| v.as_str())
+    .filter(|s| !s.is_empty())
+    .unwrap_or("customer_invoice");
+
+  // "order_details" has its own endpoint, every other template goes through send_email
+  let mut request_body = request_body_without_empty_values(&input_data, &["orderId", "template_id"])?;
+  let endpoint = if template_id == "order_details" {
+    format!("/orders/{}/actions/send_order_details", order_id)
+  } else {
+    request_body["template_id"] = json!(template_id);
+    format!("/orders/{}/actions/send_email", order_id)
+  };
+
+  let (status, response_body) = client.post(&endpoint, &request_body)?;
+
+  if status == 404 && is_missing_route(&response_body) {
+    return Err(AppError {
+      code: ErrorCode::Unsupported,
+      message: "WooCommerce-butiken saknar stöd för orderåtgärder (kräver WooCommerce 9.8 eller senare)".to_string(),
+    });
+  }
+
+  if status >= 400 {
+    return Err(AppError {
+      code: ErrorCode::Other,
+      message: format!("WooCommerce returnerade felkod {}: {}", status, response_body),
+    });
+  }
+
+  let response_json: Value = serde_json::from_str(&response_body).map_err(|e| AppError {
+    code: ErrorCode::MalformedResponse,
+    message: format!("Misslyckades att tolka JSON-svar: {}", e),
+  })?;
+
+  let message = response_json.get("message")
+    .and_then(|v| v.as_str())
+    .unwrap_or_default();
+
+  Ok(json!({
+    "order_id": order_id,
+    "template_id": template_id,
+    "message": message
+  }))
+}
+
+/// WordPress answers unknown REST routes with `rest_no_route`, which is how
+/// older WooCommerce versions respond to the order actions endpoints
+fn is_missing_route(response_body: &str) -> bool {
+  serde_json::from_str::<Value>(response_body)
+    .ok()
+    .and_then(|v| v.get("code").and_then(|c| c.as_str()).map(|c| c == "rest_no_route"))
+    .unwrap_or(false)
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
@@ -0,0 +1,97 @@
+#[allow(unused_imports)]
+use crate::standout::app::types::{AppError, ErrorCode, ActionContext};
+use crate::client::ApiClient;
+use crate::actions::utils::{meta_data_changes, parse_response, product_endpoint, required_id_parameter};
+use serde_json::{json, Map, Value};
+
+#[allow(dead_code)]
+fn client(context: &ActionContext) -> Result<ApiClient, AppError> {
+  let connection_data: serde_json::Value =
+    serde_json::from_str(&context.connection.serialized_data).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Invalid connection configuration: {}", e),
+    })?;
+  ApiClient::new(&connection_data)
+}
+
+#[allow(dead_code)]
+fn input_data(context: &ActionContext) -> Result<Value, AppError> {
+  serde_json::from_str(&context.serialized_input).map_err(|e| AppError {
+    code: ErrorCode::Other,
+    message: format!("Invalid input data: {}", e),
+  })
+}
+
+#[allow(dead_code)]
+pub fn execute(context: ActionContext) -> Result<Value, AppError> {
+  let client = client(&context)?;
+  let input_data = input_data(&context)?;
+
+  let resource = input_data.get("resource")
+    .and_then(|v| v.as_str())
+    .unwrap_or_default();
+  let id = required_id_parameter(&input_data, "id")?;
+
+  let mut endpoint = match resource {
+    "product" => format!("/products/{}", id),
+    "order" => format!("/orders/{}", id),
+    "customer" => format!("/customers/{}", id),
+    other => {
+      return Err(AppError {
+        code: ErrorCode::Misconfigured,
+        message: format!("Unknown resource '{}', expected product, order or customer", other),
+      });
+    }
+  };
+
+  let (status, body) = client.get(&endpoint)?;
+  let mut entity = parse_response(status, &body)?;
+
+  // Variations are read through /products/{id} but written through their parent
+  if resource == "product" {
+    endpoint = product_endpoint(&entity);
+  }
+
+  let changes = meta_data_changes(
+    entity.get("meta_data").unwrap_or(&Value::Null),
+    input_data.get("meta_set"),
+    input_data.get("meta_delete"),
+  );
+
+  let mut changed_keys: Vec<&str> = changes.iter().filter_map(|change| change["key"].as_str()).collect();
+  changed_keys.dedup();
+
+  if !changes.is_empty() {
+    let (status, body) = client.put(&endpoint, &json!({ "meta_data": changes }))?;
+    entity = parse_response(status, &body)?;
+  }
+
+  let meta_data = entity.get("meta_data").cloned().unwrap_or(json!([]));
+  let meta: Map<String, Value> = meta_data.as_array()
+    .into_iter()
+    .flatten()
+    .filter_map(|entry| Some((entry.get("key")?.as_str()?.to_string(), entry.get("value")?.clone())))
+    .collect();
+
+  Ok(json!({
+    "resource": resource,
+    "id": id,
+    "changed_keys": changed_keys,
+    "meta": meta,
+    "meta_data": meta_data
+  }))
+}
+
+#[allow(dead_code)]
+pub fn input_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_input_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
+
+#[allow(dead_code)]
+pub fn output_schema(_context: &ActionContext) -> Result<serde_json::Value, AppError> {
+  let base_schema = include_str!("base_output_schema.json");
+  let schema: serde_json::Value = serde_json::from_str(base_schema).unwrap();
+  Ok(schema)
+}
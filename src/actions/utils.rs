@@ -1,4 +1,5 @@
-use crate::standout::app::types::AppError;
+use crate::client::ApiClient;
+use crate::standout::app::types::{AppError, ErrorCode};
 use serde_json::{Value, json};
 
 /// Recursively clean empty values from JSON data
@@ -70,3 +71,431 @@ pub fn request_body_without_empty_values(
 
     Ok(json!(body))
 }
+
+/// Remove fields from a WooCommerce customer that should never leave the connector
+/// This strips the password and the REST API `_links` object
+#[allow(dead_code)] // Used by generated actions
+pub fn filter_customer_data(mut customer: Value) -> Value {
+    if let Some(obj) = customer.as_object_mut() {
+        obj.remove("password");
+
+        obj.remove("_links");
+    }
+    customer
+}
+
+/// Append a query parameter from input data to a list of `key=value` parts
+/// Strings are URL encoded, arrays are joined with commas and empty values are skipped
+#[allow(dead_code)] // Used by generated actions
+pub fn add_query_parameter(input_data: &Value, param_name: &str, query_parts: &mut Vec<String>) {
+    if let Some(value) = input_data.get(param_name) {
+        match value {
+            Value::String(s) if !s.is_empty() => {
+                query_parts.push(format!("{}={}", param_name, urlencoding::encode(s)));
+            }
+            Value::Array(arr) if !arr.is_empty() => {
+                let values: Vec<String> = arr
+                    .iter()
+                    .filter_map(|v| match v {
+                        Value::String(s) if !s.is_empty() => Some(s.clone()),
+                        Value::Number(n) => Some(n.to_string()),
+                        _ => None,
+                    })
+                    .collect();
+
+                if !values.is_empty() {
+                    let joined = values.join(",");
+                    query_parts.push(format!("{}={}", param_name, urlencoding::encode(&joined)));
+                }
+            }
+            Value::Number(n) => {
+                query_parts.push(format!("{}={}", param_name, n));
+            }
+            Value::Bool(b) => {
+                query_parts.push(format!("{}={}", param_name, b));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Resolve a 404 from WooCommerce according to the `on_not_found` strategy
+/// `continue` returns an empty object, `exit_level` and `exit_execution` stop the
+/// workflow and anything else fails with a message naming the resource and endpoint
+#[allow(dead_code)] // Used by generated actions
+pub fn handle_not_found(on_not_found: &str, resource: &str, endpoint: &str) -> Result<Value, AppError> {
+    match on_not_found {
+        "continue" => Ok(json!({})),
+        "exit_level" => Err(AppError {
+            code: ErrorCode::CompleteParent,
+            message: format!("Stopping current level as {} was not found", resource.to_lowercase()),
+        }),
+        "exit_execution" => Err(AppError {
+            code: ErrorCode::CompleteWorkflow,
+            message: format!("Stopping entire execution as {} was not found", resource.to_lowercase()),
+        }),
+        _ => Err(AppError {
+            code: ErrorCode::Other,
+            message: format!("{} not found (404) at {}", resource, endpoint),
+        }),
+    }
+}
+
+/// Fetch every page of a WooCommerce collection endpoint
+/// The endpoint may already contain a query string, `page` and `per_page` are appended
+/// Fetching stops at the first page with fewer than 100 items or after `max_pages` pages
+#[allow(dead_code)] // Used by generated actions
+pub fn get_all_pages(client: &ApiClient, endpoint: &str, max_pages: u32) -> Result<Vec<Value>, AppError> {
+    get_all_pages_with_more(client, endpoint, max_pages).map(|(items, _)| items)
+}
+
+/// Same as `get_all_pages`, but also tells whether fetching stopped at `max_pages` while the
+/// last page was full, so the result may be missing items
+#[allow(dead_code)] // Used by generated actions
+pub fn get_all_pages_with_more(client: &ApiClient, endpoint: &str, max_pages: u32) -> Result<(Vec<Value>, bool), AppError> {
+    let per_page = 100;
+    let separator = if endpoint.contains('?') { '&' } else { '?' };
+    let mut items = Vec::new();
+
+    for page in 1..=max_pages {
+        let page_endpoint = format!("{}{}page={}&per_page={}", endpoint, separator, page, per_page);
+        let (status, body) = client.get(&page_endpoint)?;
+
+        if status >= 400 {
+            return Err(AppError {
+                code: ErrorCode::Other,
+                message: format!("WooCommerce returnerade felkod {}: {}", status, body),
+            });
+        }
+
+        let page_items: Vec<Value> = serde_json::from_str(&body).map_err(|e| AppError {
+            code: ErrorCode::MalformedResponse,
+            message: format!("Misslyckades att tolka JSON-svar: {}", e),
+        })?;
+
+        let fetched_count = page_items.len();
+        items.extend(page_items);
+
+        if fetched_count < per_page {
+            return Ok((items, false));
+        }
+    }
+
+    Ok((items, max_pages > 0))
+}
+
+/// Turn a WooCommerce response into JSON, failing on error status codes
+#[allow(dead_code)] // Used by generated actions
+pub fn parse_response(status: u16, body: &str) -> Result<Value, AppError> {
+    if status >= 400 {
+        return Err(AppError {
+            code: ErrorCode::Other,
+            message: format!("WooCommerce returnerade felkod {}: {}", status, body),
+        });
+    }
+
+    serde_json::from_str(body).map_err(|e| AppError {
+        code: ErrorCode::MalformedResponse,
+        message: format!("Misslyckades att tolka JSON-svar: {}", e),
+    })
+}
+
+/// Turn a response from the WordPress REST API (`wp/v2`) into JSON
+/// WordPress only accepts WooCommerce consumer keys on the WooCommerce endpoints, so a 401 means
+/// the connection has to authenticate with a WordPress application password instead
+#[allow(dead_code)] // Used by generated actions
+pub fn parse_wordpress_response(status: u16, body: &str) -> Result<Value, AppError> {
+    if status == 401 {
+        return Err(AppError {
+            code: ErrorCode::Misconfigured,
+            message: format!(
+                "WordPress rejected the connection's credentials (401): {}. The wp/v2 API doesn't accept WooCommerce consumer keys, connect with a WordPress username and application password instead",
+                body
+            ),
+        });
+    }
+
+    parse_response(status, body)
+}
+
+/// Look up a single product or variation by a unique field such as `sku`, `slug` or `global_unique_id`
+/// WooCommerce ignores filters it does not know, so the match is verified against the returned items
+#[allow(dead_code)] // Used by generated actions
+pub fn find_product_by(client: &ApiClient, field: &str, value: &str) -> Result<Option<Value>, AppError> {
+    let endpoint = format!("/products?{}={}", field, urlencoding::encode(value));
+    let (status, body) = client.get(&endpoint)?;
+
+    let products = parse_response(status, &body)?;
+
+    Ok(products
+        .as_array()
+        .and_then(|items| {
+            items
+                .iter()
+                .find(|item| item.get(field).and_then(|v| v.as_str()) == Some(value))
+        })
+        .cloned())
+}
+
+/// Variations have to be updated through their parent product
+#[allow(dead_code)] // Used by generated actions
+pub fn product_endpoint(product: &Value) -> String {
+    let id = product.get("id").and_then(|v| v.as_i64()).unwrap_or_default();
+    let parent_id = product.get("parent_id").and_then(|v| v.as_i64()).unwrap_or_default();
+
+    if product.get("type").and_then(|v| v.as_str()) == Some("variation") && parent_id > 0 {
+        format!("/products/{}/variations/{}", parent_id, id)
+    } else {
+        format!("/products/{}", id)
+    }
+}
+
+/// Read an ID parameter that may arrive as either a number or a numeric string
+#[allow(dead_code)] // Used by generated actions
+pub fn id_parameter(input_data: &Value, name: &str) -> Option<i64> {
+    input_data
+        .get(name)
+        .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.trim().parse::<i64>().ok())))
+}
+
+/// Same as `id_parameter` but fails with a misconfiguration error when the ID is missing
+#[allow(dead_code)] // Used by generated actions
+pub fn required_id_parameter(input_data: &Value, name: &str) -> Result<i64, AppError> {
+    id_parameter(input_data, name).ok_or_else(|| AppError {
+        code: ErrorCode::Misconfigured,
+        message: format!("{} parameter is required", name),
+    })
+}
+
+/// Wrap the schema of a single item in an output schema with an `items` array
+/// Action outputs must be JSON objects, so list actions return `{ "items": [...] }`
+#[allow(dead_code)] // Used by generated actions
+pub fn items_output_schema(item_schema: &str, title: &str, description: &str) -> Result<Value, AppError> {
+    let mut item: Value = serde_json::from_str(item_schema).map_err(|e| AppError {
+        code: ErrorCode::Other,
+        message: format!("Failed to parse output schema: {}", e),
+    })?;
+    if let Some(obj) = item.as_object_mut() {
+        obj.remove("$schema");
+    }
+
+    Ok(json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "additionalProperties": false,
+        "type": "object",
+        "properties": {
+            "items": {
+                "type": "array",
+                "title": title,
+                "description": description,
+                "items": item
+            }
+        }
+    }))
+}
+
+/// Compare a term, tag, category or shipping class name returned by WooCommerce with a wanted name
+/// WordPress stores names HTML-escaped, so "Black & White" comes back as "Black &amp; White"
+#[allow(dead_code)] // Used by generated actions
+pub fn term_name_matches(stored: &str, wanted: &str) -> bool {
+    let normalize = |name: &str| {
+        name.replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#039;", "'")
+            .replace("&#39;", "'")
+            .replace("&amp;", "&")
+            .trim()
+            .to_lowercase()
+    };
+    normalize(stored) == normalize(wanted)
+}
+
+/// Replace tag entries given by name with `{ "id": .. }`, creating tags that do not exist yet
+/// Entries may be `{ "id": 1 }`, `{ "name": "Rea" }` or a plain string; WooCommerce itself only accepts ids
+#[allow(dead_code)] // Used by generated actions
+pub fn resolve_tag_names(client: &ApiClient, body: &mut Value) -> Result<(), AppError> {
+    let Some(tags) = body.get_mut("tags").and_then(|v| v.as_array_mut()) else {
+        return Ok(());
+    };
+
+    for tag in tags.iter_mut() {
+        if tag.get("id").and_then(|v| v.as_i64()).is_some() {
+            continue;
+        }
+
+        let name = tag
+            .as_str()
+            .or_else(|| tag.get("name").and_then(|v| v.as_str()))
+            .map(|name| name.trim())
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| AppError {
+                code: ErrorCode::Misconfigured,
+                message: "Each tag needs an id or a name".to_string(),
+            })?;
+
+        let endpoint = format!("/products/tags?search={}", urlencoding::encode(name));
+
+        // Search is a substring match and names come back HTML-escaped
+        let existing = get_all_pages(client, &endpoint, 10)?.into_iter().find(|candidate| {
+            candidate
+                .get("name")
+                .and_then(|v| v.as_str())
+                .is_some_and(|n| term_name_matches(n, name))
+        });
+
+        let resolved = match existing {
+            Some(resolved) => resolved,
+            None => {
+                let (status, body) = client.post("/products/tags", &json!({ "name": name }))?;
+                parse_response(status, &body)?
+            }
+        };
+
+        *tag = json!({ "id": resolved.get("id") });
+    }
+
+    Ok(())
+}
+
+/// Send updates to a WooCommerce batch endpoint in chunks of 100 and return the per-item results in order
+/// Items that WooCommerce rejects come back with an `error` object instead of failing the request
+#[allow(dead_code)] // Used by generated actions
+pub fn batch_update(client: &ApiClient, endpoint: &str, updates: &[Value]) -> Result<Vec<Value>, AppError> {
+    batch_write(client, endpoint, "update", updates)
+}
+
+/// Create items through a WooCommerce batch endpoint in chunks of 100. A chunk that fails as a whole is
+/// reported as an `error` on each of its items, so the items created by other chunks still reach the caller
+#[allow(dead_code)] // Used by generated actions
+pub fn batch_create_reporting_failures(client: &ApiClient, endpoint: &str, items: &[Value]) -> Vec<Value> {
+    items
+        .chunks(100)
+        .flat_map(|chunk| match batch_chunk(client, endpoint, "create", chunk) {
+            Ok(results) => results,
+            Err(e) => {
+                let error = json!({ "error": { "code": "batch_request_failed", "message": e.message } });
+                vec![error; chunk.len()]
+            }
+        })
+        .collect()
+}
+
+fn batch_write(client: &ApiClient, endpoint: &str, operation: &str, items: &[Value]) -> Result<Vec<Value>, AppError> {
+    let mut results = Vec::new();
+
+    for chunk in items.chunks(100) {
+        results.extend(batch_chunk(client, endpoint, operation, chunk)?);
+    }
+
+    Ok(results)
+}
+
+fn batch_chunk(client: &ApiClient, endpoint: &str, operation: &str, chunk: &[Value]) -> Result<Vec<Value>, AppError> {
+    let (status, body) = client.post(endpoint, &json!({ operation: chunk }))?;
+    let response = parse_response(status, &body)?;
+    let results = response.get(operation).and_then(|v| v.as_array()).cloned().unwrap_or_default();
+
+    // Results are matched to the sent items by position, so a short answer can't be trusted
+    if results.len() != chunk.len() {
+        return Err(AppError {
+            code: ErrorCode::MalformedResponse,
+            message: format!(
+                "WooCommerce returned {} results for {} items sent to {}",
+                results.len(),
+                chunk.len(),
+                endpoint
+            ),
+        });
+    }
+
+    Ok(results)
+}
+
+/// The error WooCommerce reported for one batch item as `{ code, message }`, None if it succeeded
+#[allow(dead_code)] // Used by generated actions
+pub fn batch_item_error(result: &Value) -> Option<Value> {
+    result.get("error").map(|error| {
+        json!({
+            "code": error.get("code"),
+            "message": error.get("message")
+        })
+    })
+}
+
+/// WooCommerce sets the shipping class by slug, so a class given by name is replaced with its slug
+/// An empty value is left alone since it removes the shipping class
+#[allow(dead_code)] // Used by generated actions
+pub fn resolve_shipping_class(client: &ApiClient, body: &mut Value) -> Result<(), AppError> {
+    let Some(wanted) = body
+        .get("shipping_class")
+        .and_then(|v| v.as_str())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+    else {
+        return Ok(());
+    };
+
+    let classes = get_all_pages(client, "/products/shipping_classes", 10)?;
+    let field = |class: &Value, name: &str| class.get(name).and_then(|v| v.as_str()).unwrap_or_default().to_string();
+
+    let slug = classes
+        .iter()
+        .find(|class| field(class, "slug") == wanted)
+        .or_else(|| {
+            classes.iter().find(|class| term_name_matches(&field(class, "name"), &wanted))
+        })
+        .map(|class| field(class, "slug"))
+        .ok_or_else(|| AppError {
+            code: ErrorCode::Misconfigured,
+            message: format!("Shipping class '{}' not found by slug or name", wanted),
+        })?;
+
+    body["shipping_class"] = json!(slug);
+    Ok(())
+}
+
+/// Translate `meta_set` (key/value map) and `meta_delete` (list of keys) into `meta_data` entries.
+/// Existing entries are addressed by id so keys are not duplicated, extra entries for a key that is
+/// set are removed, and deleted keys are sent with a null value. Keys that already hold the wanted
+/// value produce no entry, so an empty result means nothing needs to be written.
+#[allow(dead_code)] // Used by generated actions
+pub fn meta_data_changes(current_meta: &Value, meta_set: Option<&Value>, meta_delete: Option<&Value>) -> Vec<Value> {
+    let existing = current_meta.as_array().cloned().unwrap_or_default();
+    let entries_for = |key: &str| -> Vec<&Value> {
+        existing
+            .iter()
+            .filter(|entry| entry.get("key").and_then(|v| v.as_str()) == Some(key))
+            .collect()
+    };
+    let text = |value: &Value| match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+
+    let mut changes = Vec::new();
+
+    for (key, value) in meta_set.and_then(|v| v.as_object()).into_iter().flatten() {
+        let entries = entries_for(key);
+
+        match entries.split_first() {
+            None => changes.push(json!({ "key": key, "value": value })),
+            Some((first, duplicates)) => {
+                if first.get("value").map(text) != Some(text(value)) {
+                    changes.push(json!({ "id": first.get("id"), "key": key, "value": value }));
+                }
+                for duplicate in duplicates {
+                    changes.push(json!({ "id": duplicate.get("id"), "key": key, "value": null }));
+                }
+            }
+        }
+    }
+
+    for key in meta_delete.and_then(|v| v.as_array()).into_iter().flatten().filter_map(|v| v.as_str()) {
+        for entry in entries_for(key) {
+            changes.push(json!({ "id": entry.get("id"), "key": key, "value": null }));
+        }
+    }
+
+    changes
+}
@@ -40,6 +40,21 @@ impl ApiClient {
     Ok(ApiClient { base_url, headers })
   }
 
+  /// Client for another REST namespace on the same site, e.g. `wp/v2` for the media library.
+  /// The connection's base_url points at `/wp-json/wc/v3`, which is swapped for the given namespace.
+  pub fn for_namespace(&self, namespace: &str) -> ApiClient {
+    let clean_base = self.base_url.trim_end_matches('/');
+    let site = match clean_base.find("/wp-json") {
+      Some(index) => &clean_base[..index],
+      None => clean_base,
+    };
+
+    ApiClient {
+      base_url: format!("{}/wp-json/{}", site, namespace.trim_matches('/')),
+      headers: self.headers.clone(),
+    }
+  }
+
   fn build_url(&self, endpoint: &str) -> String {
     let clean_base = self.base_url.trim_end_matches('/');
     let clean_endpoint = endpoint.trim_start_matches('/');
@@ -112,6 +127,24 @@ impl ApiClient {
   }
 
   pub fn delete(&self, endpoint: &str) -> Result<Value, AppError> {
+    let (status, body) = self.delete_with_status(endpoint)?;
+
+    if status >= 400 {
+      return Err(AppError {
+        code: ErrorCode::Other,
+        message: format!("Servern svarade med fel {}: {}", status, body),
+      });
+    }
+
+    let json_response: Value = serde_json::from_str(&body).map_err(|e| AppError {
+      code: ErrorCode::Other,
+      message: format!("Kunde inte tolka raderings-svaret som JSON: {}", e),
+    })?;
+
+    Ok(json_response)
+  }
+
+  pub fn delete_with_status(&self, endpoint: &str) -> Result<(u16, String), AppError> {
     let url = self.build_url(endpoint);
 
     let mut request_builder = RequestBuilder::new()
@@ -127,18 +160,6 @@ impl ApiClient {
       message: format!("DELETE-anrop misslyckades till URL: {}", url),
     })?;
 
-    if response.status >= 400 {
-      return Err(AppError {
-        code: ErrorCode::Other,
-        message: format!("Servern svarade med fel {}: {}", response.status, response.body),
-      });
-    }
-
-    let json_response: Value = serde_json::from_str(&response.body).map_err(|e| AppError {
-      code: ErrorCode::Other,
-      message: format!("Kunde inte tolka raderings-svaret som JSON: {}", e),
-    })?;
-
-    Ok(json_response)
+    Ok((response.status, response.body))
   }
 }